@@ -69,7 +69,7 @@ async fn main() -> Result<()> {
     let created_variation: ProductVariation =
         client.create_subentity(created.id, variation).await?;
     info!(
-        "Variation {} created with price: {}",
+        "Variation {} created with price: {:?}",
         created_variation.sku, created_variation.price
     );
     let update = ProductVariation::builder().regular_price("7000").build();
@@ -77,7 +77,7 @@ async fn main() -> Result<()> {
         .update_subentity(created.id, created_variation.id, update)
         .await?;
     info!(
-        "Variation {} updated with price: {}",
+        "Variation {} updated with price: {:?}",
         updated_variation.sku, updated_variation.price
     );
     let deleted_variation: ProductVariation = client
@@ -1,11 +1,16 @@
 use anyhow::Result;
 use serde::Deserialize;
 
+use crate::StoreConfig;
+
 /// Configuration struct for storing Woo parameters
 #[derive(Deserialize)]
 pub struct Config {
     /// The Woo struct containing ck, cs, and host strings
     pub woo: Woo,
+    /// Desired store configuration, empty when the `[store]` section is missing
+    #[serde(default)]
+    pub store: StoreConfig,
 }
 
 impl Config {
@@ -0,0 +1,255 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::{ApiClient, Error, Result};
+
+use super::{
+    auth::Auth, cache::ResponseCache, limiter::Limiter, retry::RetryPolicy, transport::Transport,
+};
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// REST API namespace the client talks to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ApiNamespace {
+    /// `/wp-json/wc/v3/`
+    #[default]
+    WcV3,
+    /// `/wp-json/wc/v2/`
+    WcV2,
+    /// `/wp-json/wc-analytics/`
+    WcAnalytics,
+}
+impl ApiNamespace {
+    /// Path of the namespace relative to the site root
+    pub fn path(&self) -> &'static str {
+        match self {
+            ApiNamespace::WcV3 => "wp-json/wc/v3/",
+            ApiNamespace::WcV2 => "wp-json/wc/v2/",
+            ApiNamespace::WcAnalytics => "wp-json/wc-analytics/",
+        }
+    }
+}
+/// Builder for [`ApiClient`]
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use rust_woocommerce::{ApiClient, ApiNamespace};
+///
+/// # fn main() -> rust_woocommerce::Result<()> {
+/// let client = ApiClient::builder()
+///     .host("shop.example.com")
+///     .credentials("ck_...", "cs_...")
+///     .namespace(ApiNamespace::WcV3)
+///     .timeout(Duration::from_secs(30))
+///     .user_agent("my-app/1.0")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct ApiClientBuilder {
+    host: Option<String>,
+    ck: Option<String>,
+    cs: Option<String>,
+    namespace: ApiNamespace,
+    auth: Auth,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: Option<reqwest::Proxy>,
+    default_headers: Vec<(String, String)>,
+    client: Option<reqwest::Client>,
+    transport: Option<Arc<dyn Transport>>,
+    retry_policy: RetryPolicy,
+    concurrency_limit: Option<usize>,
+    rate_limit: Option<u32>,
+    cache: Option<ResponseCache>,
+    strict: bool,
+}
+impl ApiClientBuilder {
+    /// Store address, with or without scheme. https is used when the scheme is missing.
+    pub fn host(mut self, host: impl Into<String>) -> Self {
+        let _ = self.host.insert(host.into());
+        self
+    }
+    /// Consumer key and consumer secret of the REST API key.
+    pub fn credentials(mut self, ck: impl Into<String>, cs: impl Into<String>) -> Self {
+        let _ = self.ck.insert(ck.into());
+        let _ = self.cs.insert(cs.into());
+        self
+    }
+    /// REST API namespace. Default is wc/v3.
+    pub fn namespace(mut self, namespace: ApiNamespace) -> Self {
+        self.namespace = namespace;
+        self
+    }
+    /// Authentication strategy. Default is [`Auth::Auto`], chosen from the URL scheme.
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+    /// Timeout for establishing a connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        let _ = self.connect_timeout.insert(timeout);
+        self
+    }
+    /// Timeout for the whole request, from connecting until the response body is read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        let _ = self.timeout.insert(timeout);
+        self
+    }
+    /// User-Agent header sent with every request. Default is `rust-woocommerce/<version>`.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        let _ = self.user_agent.insert(user_agent.into());
+        self
+    }
+    /// Proxy used for all requests.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        let _ = self.proxy.insert(proxy);
+        self
+    }
+    /// Header sent with every request, may be called multiple times.
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+    /// Use a preconfigured reqwest Client.
+    ///
+    /// Cannot be combined with timeouts, user agent, proxy or default headers, those have to be set on the client itself.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        let _ = self.client.insert(client);
+        self
+    }
+    /// Send requests through a custom transport, e.g. [`crate::ReplayTransport`] in tests.
+    ///
+    /// Like [`ApiClientBuilder::client`], cannot be combined with timeouts, user agent, proxy or default headers.
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        let _ = self.transport.insert(Arc::new(transport));
+        self
+    }
+    /// Policy used to retry failed requests.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+    /// See [`ApiClient::with_concurrency_limit`].
+    pub fn concurrency_limit(mut self, max_in_flight: usize) -> Self {
+        let _ = self.concurrency_limit.insert(max_in_flight);
+        self
+    }
+    /// See [`ApiClient::with_rate_limit`].
+    pub fn rate_limit(mut self, requests_per_second: u32) -> Self {
+        let _ = self.rate_limit.insert(requests_per_second);
+        self
+    }
+    /// See [`ApiClient::with_cache`].
+    pub fn cache(mut self, cache: ResponseCache) -> Self {
+        let _ = self.cache.insert(cache);
+        self
+    }
+    /// Reject WooCommerce quirks and unknown fields in responses instead of tolerating them, see [`crate::strict`].
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+    /// Build the client, failing if host or credentials are missing or any option is invalid.
+    pub fn build(self) -> Result<ApiClient> {
+        let host = self
+            .host
+            .ok_or_else(|| Error::Config(String::from("host is not set")))?;
+        let (Some(ck), Some(cs)) = (self.ck, self.cs) else {
+            return Err(Error::Config(String::from("credentials are not set")));
+        };
+        let base_url = parse_host(&host)?.join(self.namespace.path())?;
+        let has_transport_options = self.connect_timeout.is_some()
+            || self.timeout.is_some()
+            || self.user_agent.is_some()
+            || self.proxy.is_some()
+            || !self.default_headers.is_empty();
+        if self.transport.is_some() && has_transport_options {
+            return Err(Error::Config(String::from(
+                "timeouts, user agent, proxy and default headers cannot be combined with a custom transport",
+            )));
+        }
+        let client = match self.client {
+            Some(_) if has_transport_options => {
+                return Err(Error::Config(String::from(
+                    "timeouts, user agent, proxy and default headers cannot be combined with a custom client",
+                )))
+            }
+            Some(client) => client,
+            None => {
+                let mut headers = HeaderMap::new();
+                for (name, value) in self.default_headers {
+                    let name = HeaderName::try_from(name.as_str())
+                        .map_err(|e| Error::Config(format!("header {name}: {e}")))?;
+                    let value = HeaderValue::try_from(value.as_str())
+                        .map_err(|e| Error::Config(format!("header {name}: {e}")))?;
+                    headers.append(name, value);
+                }
+                let mut builder = reqwest::Client::builder()
+                    .gzip(true)
+                    .user_agent(self.user_agent.as_deref().unwrap_or(USER_AGENT))
+                    .default_headers(headers);
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+                builder.build()?
+            }
+        };
+        let transport = self
+            .transport
+            .unwrap_or_else(|| Arc::new(client.clone()) as Arc<dyn Transport>);
+        let mut api_client = ApiClient {
+            ck,
+            cs,
+            base_url,
+            client,
+            transport,
+            retry_policy: self.retry_policy,
+            limiter: Limiter::default(),
+            auth: self.auth,
+            cache: self.cache,
+            strict: self.strict,
+        };
+        if let Some(max_in_flight) = self.concurrency_limit {
+            api_client = api_client.with_concurrency_limit(max_in_flight);
+        }
+        if let Some(requests_per_second) = self.rate_limit {
+            api_client = api_client.with_rate_limit(requests_per_second);
+        }
+        Ok(api_client)
+    }
+}
+/// Parse store address, defaulting to https and keeping the path of sites installed in a subdirectory
+fn parse_host(host: &str) -> Result<url::Url> {
+    let host = host.trim();
+    let mut url = match url::Url::parse(host) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => url,
+        _ => url::Url::parse(&format!("https://{host}"))?,
+    };
+    if !url.path().ends_with('/') {
+        let path = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+    url.set_query(None);
+    url.set_fragment(None);
+    Ok(url)
+}
+impl ApiClient {
+    /// Start building a client with custom transport options
+    pub fn builder() -> ApiClientBuilder {
+        ApiClientBuilder::default()
+    }
+}
@@ -0,0 +1,382 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use reqwest::header::{HeaderValue, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::{Method, StatusCode};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{ApiClient, Error, Result};
+
+use super::transport::{build_response, header_pairs};
+use super::Entity;
+
+/// Successful GET response kept by a [`CacheStore`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedResponse {
+    /// Request URL, without credentials.
+    pub url: String,
+    /// First path segment below the API namespace, e.g. `products`, used for invalidation.
+    pub resource: String,
+    /// HTTP status.
+    pub status: u16,
+    /// Response headers, including the pagination headers.
+    pub headers: Vec<(String, String)>,
+    /// Response body.
+    pub body: String,
+    /// When the response was fetched or last revalidated.
+    pub stored_at: DateTime<Utc>,
+}
+impl CachedResponse {
+    /// First value of a header, names are case insensitive
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+    fn is_fresh(&self, ttl: Duration) -> bool {
+        (Utc::now() - self.stored_at)
+            .to_std()
+            .is_ok_and(|age| age < ttl)
+    }
+    async fn read(url: String, resource: String, response: reqwest::Response) -> Result<Self> {
+        let status = response.status().as_u16();
+        let headers = header_pairs(response.headers());
+        let body = response.text().await?;
+        Ok(CachedResponse {
+            url,
+            resource,
+            status,
+            headers,
+            body,
+            stored_at: Utc::now(),
+        })
+    }
+    fn to_response(&self) -> reqwest::Response {
+        build_response(self.status, &self.headers, self.body.clone())
+    }
+}
+/// Storage backend of a [`ResponseCache`].
+///
+/// Implement it over a shared store such as Redis when several instances should share cached responses.
+pub trait CacheStore: Send + Sync {
+    /// Response cached for a URL of a resource
+    fn get<'a>(
+        &'a self,
+        resource: &'a str,
+        url: &'a str,
+    ) -> BoxFuture<'a, Result<Option<CachedResponse>>>;
+    /// Cache a response under its URL
+    fn put<'a>(&'a self, response: &'a CachedResponse) -> BoxFuture<'a, Result<()>>;
+    /// Drop every response of a resource
+    fn invalidate<'a>(&'a self, resource: &'a str) -> BoxFuture<'a, Result<()>>;
+}
+/// In-process [`CacheStore`]
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    responses: Mutex<HashMap<String, CachedResponse>>,
+}
+impl MemoryCache {
+    /// Empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl CacheStore for MemoryCache {
+    fn get<'a>(
+        &'a self,
+        _resource: &'a str,
+        url: &'a str,
+    ) -> BoxFuture<'a, Result<Option<CachedResponse>>> {
+        let responses = self.responses.lock().unwrap_or_else(|e| e.into_inner());
+        Box::pin(futures::future::ready(Ok(responses.get(url).cloned())))
+    }
+    fn put<'a>(&'a self, response: &'a CachedResponse) -> BoxFuture<'a, Result<()>> {
+        let mut responses = self.responses.lock().unwrap_or_else(|e| e.into_inner());
+        responses.insert(response.url.clone(), response.clone());
+        Box::pin(futures::future::ready(Ok(())))
+    }
+    fn invalidate<'a>(&'a self, resource: &'a str) -> BoxFuture<'a, Result<()>> {
+        let mut responses = self.responses.lock().unwrap_or_else(|e| e.into_inner());
+        responses.retain(|_, response| response.resource != resource);
+        Box::pin(futures::future::ready(Ok(())))
+    }
+}
+/// [`CacheStore`] keeping one JSON file per response in a directory, so that the cache survives restarts
+#[derive(Debug)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+impl DiskCache {
+    /// Cache in the given directory, created on the first write
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        DiskCache { dir: dir.into() }
+    }
+    /// File of a URL, prefixed with the resource so that it can be invalidated without reading every file
+    fn path(&self, resource: &str, url: &str) -> PathBuf {
+        let digest = Sha256::digest(url.as_bytes());
+        let hash: String = digest[..16].iter().map(|b| format!("{b:02x}")).collect();
+        self.dir
+            .join(format!("{}-{hash}.json", file_prefix(resource)))
+    }
+    fn read(&self, resource: &str, url: &str) -> Result<Option<CachedResponse>> {
+        let path = self.path(resource, url);
+        let payload = match std::fs::read_to_string(path) {
+            Ok(payload) => payload,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let response: CachedResponse = serde_json::from_str(&payload)
+            .map_err(|source| Error::Deserialize { source, payload })?;
+        Ok(Some(response).filter(|response| response.url == url))
+    }
+    fn write(&self, response: &CachedResponse) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.path(&response.resource, &response.url);
+        let payload = serde_json::to_vec(response).map_err(std::io::Error::from)?;
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, payload)?;
+        std::fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+    fn remove(&self, resource: &str) -> Result<()> {
+        let prefix = format!("{}-", file_prefix(resource));
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_name().to_string_lossy().starts_with(&prefix) {
+                std::fs::remove_file(entry.path())?;
+            }
+        }
+        Ok(())
+    }
+}
+impl CacheStore for DiskCache {
+    fn get<'a>(
+        &'a self,
+        resource: &'a str,
+        url: &'a str,
+    ) -> BoxFuture<'a, Result<Option<CachedResponse>>> {
+        Box::pin(futures::future::ready(self.read(resource, url)))
+    }
+    fn put<'a>(&'a self, response: &'a CachedResponse) -> BoxFuture<'a, Result<()>> {
+        Box::pin(futures::future::ready(self.write(response)))
+    }
+    fn invalidate<'a>(&'a self, resource: &'a str) -> BoxFuture<'a, Result<()>> {
+        Box::pin(futures::future::ready(self.remove(resource)))
+    }
+}
+/// Resource of a path relative to the API namespace, e.g. `taxes` for `taxes/classes/`
+fn resource_of(path: &str) -> String {
+    path.split(['/', '?']).next().unwrap_or_default().to_owned()
+}
+fn file_prefix(resource: &str) -> String {
+    resource
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+/// Caches GET responses of the entities it is configured for, revalidating expired responses with
+/// `If-None-Match`/`If-Modified-Since` when the store sent an `ETag` or `Last-Modified` header.
+///
+/// Creating, updating or deleting through the client drops the cached responses of the same resource,
+/// e.g. updating a tax rate drops the cached tax classes.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use anyhow::Result;
+/// use rust_woocommerce::{ApiClient, Config, PaymentGateway, ResponseCache, TaxClass};
+/// use tracing::info;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     tracing_subscriber::fmt::init();
+///     let config = Config::new("woo.toml")?;
+///     let cache = ResponseCache::disk(".woo-cache")
+///         .ttl::<TaxClass>(Duration::from_secs(24 * 60 * 60))
+///         .ttl::<PaymentGateway>(Duration::from_secs(60 * 60));
+///     let client = ApiClient::new(&config)?.with_cache(cache);
+///     let classes = client.list_all::<TaxClass>().await?;
+///     // Served from the cache
+///     let classes_again = client.list_all::<TaxClass>().await?;
+///     info!("Got {} and {} tax classes", classes.len(), classes_again.len());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ResponseCache {
+    store: Arc<dyn CacheStore>,
+    ttls: Vec<EntityTtl>,
+    default_ttl: Option<Duration>,
+}
+/// TTL of an entity, applied to its collection path and the paths of single entities
+#[derive(Debug, Clone)]
+struct EntityTtl {
+    /// Path segments of the collection, `None` where the ID of a parent entity goes
+    segments: Vec<Option<String>>,
+    is_id: fn(&str) -> bool,
+    ttl: Duration,
+}
+impl EntityTtl {
+    fn new<T: Entity>(ttl: Duration) -> Self {
+        let endpoint = match T::endpoint() {
+            endpoint if endpoint.is_empty() => T::child_endpoint(0),
+            endpoint => endpoint,
+        };
+        let segments = endpoint
+            .trim_end_matches('/')
+            .split('/')
+            .map(|segment| (segment != "0").then(|| segment.to_owned()))
+            .collect();
+        EntityTtl {
+            segments,
+            is_id: is_id::<T>,
+            ttl,
+        }
+    }
+    /// Whether a path is the collection, e.g. `products/`, or a single entity, e.g. `products/7`
+    fn matches(&self, path: &str) -> bool {
+        let path = path.split('?').next().unwrap_or_default();
+        let mut segments = path.split('/');
+        let collection = self.segments.iter().all(|expected| {
+            segments.next().is_some_and(|segment| match expected {
+                Some(expected) => segment == expected,
+                None => segment.parse::<i32>().is_ok(),
+            })
+        });
+        match (segments.next(), segments.next()) {
+            (Some(""), None) => collection,
+            (Some(id), None) => collection && (self.is_id)(id),
+            _ => false,
+        }
+    }
+}
+/// Whether a path segment can be the ID of entity T
+fn is_id<T: Entity>(segment: &str) -> bool {
+    serde_json::from_str::<T::Id>(segment).is_ok()
+        || serde_json::from_value::<T::Id>(serde_json::Value::from(segment)).is_ok()
+}
+impl std::fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("ttls", &self.ttls)
+            .field("default_ttl", &self.default_ttl)
+            .finish_non_exhaustive()
+    }
+}
+impl ResponseCache {
+    /// Cache backed by the given store, nothing is cached until TTLs are set
+    pub fn new(store: Arc<dyn CacheStore>) -> Self {
+        ResponseCache {
+            store,
+            ttls: Vec::new(),
+            default_ttl: None,
+        }
+    }
+    /// Cache kept in memory
+    pub fn memory() -> Self {
+        Self::new(Arc::new(MemoryCache::new()))
+    }
+    /// Cache kept in files of the given directory
+    pub fn disk(dir: impl Into<PathBuf>) -> Self {
+        Self::new(Arc::new(DiskCache::new(dir)))
+    }
+    /// Cache responses of entity T, including single entities, for the given time.
+    pub fn ttl<T: Entity>(mut self, ttl: Duration) -> Self {
+        let ttl = EntityTtl::new::<T>(ttl);
+        self.ttls.retain(|e| e.segments != ttl.segments);
+        self.ttls.push(ttl);
+        self
+    }
+    /// Cache responses of all other entities for the given time.
+    pub fn default_ttl(mut self, ttl: Duration) -> Self {
+        let _ = self.default_ttl.insert(ttl);
+        self
+    }
+    /// Drop the cached responses of entity T, e.g. after it was changed in the admin
+    pub async fn invalidate<T: Entity>(&self) -> Result<()> {
+        self.store.invalidate(&resource_of(&T::endpoint())).await
+    }
+    /// TTL of the entity a path relative to the API namespace belongs to
+    fn ttl_for(&self, path: &str) -> Option<Duration> {
+        self.ttls
+            .iter()
+            .find(|e| e.matches(path))
+            .map(|e| e.ttl)
+            .or(self.default_ttl)
+    }
+    /// Send a request through the cache, invalidating the resource after writes
+    pub(crate) async fn execute(
+        &self,
+        client: &ApiClient,
+        mut request: reqwest::Request,
+    ) -> Result<reqwest::Response> {
+        let url = request.url().to_string();
+        let path = url
+            .strip_prefix(client.base_url.as_str())
+            .unwrap_or(&url)
+            .to_owned();
+        let resource = resource_of(&path);
+        if request.method() != Method::GET {
+            let result = client.execute_request(request).await;
+            if let Err(e) = self.store.invalidate(&resource).await {
+                tracing::warn!("Failed to invalidate cached {resource}: {e}");
+            }
+            return result;
+        }
+        let Some(ttl) = self.ttl_for(&path) else {
+            return client.execute_request(request).await;
+        };
+        let cached = self.store.get(&resource, &url).await.unwrap_or_else(|e| {
+            tracing::warn!("Failed to read cached {url}: {e}");
+            None
+        });
+        if let Some(cached) = &cached {
+            if cached.is_fresh(ttl) {
+                return Ok(cached.to_response());
+            }
+            let conditions = [
+                (IF_NONE_MATCH, cached.header(ETAG.as_str())),
+                (IF_MODIFIED_SINCE, cached.header(LAST_MODIFIED.as_str())),
+            ];
+            for (name, value) in conditions {
+                if let Some(value) = value.and_then(|v| HeaderValue::try_from(v).ok()) {
+                    request.headers_mut().insert(name, value);
+                }
+            }
+        }
+        let fresh = match client.execute_request(request).await {
+            Ok(response) => CachedResponse::read(url, resource, response).await?,
+            Err(e) if e.status() == Some(StatusCode::NOT_MODIFIED) => match cached {
+                Some(cached) => CachedResponse {
+                    stored_at: Utc::now(),
+                    ..cached
+                },
+                None => return Err(e),
+            },
+            Err(e) => return Err(e),
+        };
+        if let Err(e) = self.store.put(&fresh).await {
+            tracing::warn!("Failed to cache {}: {e}", fresh.url);
+        }
+        Ok(fresh.to_response())
+    }
+}
+impl ApiClient {
+    /// Cache GET responses, see [`ResponseCache`].
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        let _ = self.cache.insert(cache);
+        self
+    }
+}
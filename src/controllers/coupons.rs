@@ -1,6 +1,12 @@
+use chrono::{DateTime, NaiveDateTime, TimeZone};
 use serde_with::skip_serializing_none;
 
-use crate::{DiscountType, MetaData};
+use crate::{Coupon, DiscountType, MetaData};
+
+use super::{
+    gmt_string,
+    query::{comma_separated, Query, SortOrder},
+};
 #[skip_serializing_none]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
 pub struct CreateCoupon {
@@ -171,6 +177,12 @@ impl<C, D, A> CreateCouponBuilder<C, D, A> {
         let _ = self.date_expires_gmt.insert(date_expires_gmt.into());
         self
     }
+    /// The date the coupon expires as an instant, sent as `date_expires_gmt` so the site's timezone does not shift it.
+    pub fn date_expires_utc<Tz: TimeZone>(mut self, date: DateTime<Tz>) -> Self {
+        let _ = self.date_expires_gmt.insert(gmt_string(date));
+        self.date_expires = None;
+        self
+    }
     /// If true, the coupon can only be used individually. Other applied coupons will be removed from the cart. Default is false.
     pub fn individual_use(mut self) -> Self {
         self.individual_use = true;
@@ -370,6 +382,12 @@ impl UpdateCouponBuilder {
         let _ = self.date_expires_gmt.insert(date_expires_gmt.into());
         self
     }
+    /// The date the coupon expires as an instant, sent as `date_expires_gmt` so the site's timezone does not shift it.
+    pub fn date_expires_utc<Tz: TimeZone>(&mut self, date: DateTime<Tz>) -> &mut Self {
+        let _ = self.date_expires_gmt.insert(gmt_string(date));
+        self.date_expires = None;
+        self
+    }
     /// If true, the coupon can only be used individually. Other applied coupons will be removed from the cart. Default is false.
     pub fn individual_use(&mut self, individual_use: bool) -> &mut Self {
         let _ = self.individual_use.insert(individual_use);
@@ -478,3 +496,91 @@ impl UpdateCouponBuilder {
         }
     }
 }
+/// Filters for listing coupons.
+#[skip_serializing_none]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
+pub struct CouponQuery {
+    search: Option<String>,
+    after: Option<NaiveDateTime>,
+    before: Option<NaiveDateTime>,
+    modified_after: Option<NaiveDateTime>,
+    modified_before: Option<NaiveDateTime>,
+    dates_are_gmt: Option<bool>,
+    #[serde(serialize_with = "comma_separated")]
+    exclude: Option<Vec<i32>>,
+    #[serde(serialize_with = "comma_separated")]
+    include: Option<Vec<i32>>,
+    order: Option<SortOrder>,
+    orderby: Option<CouponSortBy>,
+    code: Option<String>,
+}
+impl Query<Coupon> for CouponQuery {}
+impl CouponQuery {
+    /// Limit results to those matching a string.
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        let _ = self.search.insert(search.into());
+        self
+    }
+    /// Limit response to resources published after a given ISO8601 compliant date.
+    pub fn after(mut self, after: NaiveDateTime) -> Self {
+        let _ = self.after.insert(after);
+        self
+    }
+    /// Limit response to resources published before a given ISO8601 compliant date.
+    pub fn before(mut self, before: NaiveDateTime) -> Self {
+        let _ = self.before.insert(before);
+        self
+    }
+    /// Limit response to resources modified after a given ISO8601 compliant date.
+    pub fn modified_after(mut self, modified_after: NaiveDateTime) -> Self {
+        let _ = self.modified_after.insert(modified_after);
+        self
+    }
+    /// Limit response to resources modified before a given ISO8601 compliant date.
+    pub fn modified_before(mut self, modified_before: NaiveDateTime) -> Self {
+        let _ = self.modified_before.insert(modified_before);
+        self
+    }
+    /// Whether to consider GMT post dates when limiting response by published or modified date.
+    pub fn dates_are_gmt(mut self) -> Self {
+        let _ = self.dates_are_gmt.insert(true);
+        self
+    }
+    /// Ensure result set excludes specific IDs.
+    pub fn exclude(mut self, id: i32) -> Self {
+        self.exclude.get_or_insert(vec![]).push(id);
+        self
+    }
+    /// Limit result set to specific ids.
+    pub fn include(mut self, id: i32) -> Self {
+        self.include.get_or_insert(vec![]).push(id);
+        self
+    }
+    /// Order sort attribute ascending or descending. Options: asc and desc. Default is desc.
+    pub fn order(mut self, order: SortOrder) -> Self {
+        let _ = self.order.insert(order);
+        self
+    }
+    /// Sort collection by object attribute. Options: date, id, include, title and slug. Default is date.
+    pub fn orderby(mut self, orderby: CouponSortBy) -> Self {
+        let _ = self.orderby.insert(orderby);
+        self
+    }
+    /// Limit result set to resources with a specific code.
+    pub fn code(mut self, code: impl Into<String>) -> Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+}
+/// Coupon attribute to sort collection by.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CouponSortBy {
+    #[default]
+    Date,
+    Modified,
+    Id,
+    Include,
+    Title,
+    Slug,
+}
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::{Billing, MetaData, Shipping};
+use crate::{Billing, Customer, MetaData, Role, Shipping};
+
+use super::query::{comma_separated, Query, SortOrder};
 
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -325,3 +327,65 @@ impl UpdateCustomer {
         self
     }
 }
+/// Filters for listing customers.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CustomerQuery {
+    search: Option<String>,
+    #[serde(serialize_with = "comma_separated")]
+    exclude: Option<Vec<i32>>,
+    #[serde(serialize_with = "comma_separated")]
+    include: Option<Vec<i32>>,
+    order: Option<SortOrder>,
+    orderby: Option<CustomerSortBy>,
+    email: Option<String>,
+    role: Option<Role>,
+}
+impl Query<Customer> for CustomerQuery {}
+impl CustomerQuery {
+    /// Limit results to those matching a string.
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        let _ = self.search.insert(search.into());
+        self
+    }
+    /// Ensure result set excludes specific IDs.
+    pub fn exclude(mut self, id: i32) -> Self {
+        self.exclude.get_or_insert(vec![]).push(id);
+        self
+    }
+    /// Limit result set to specific IDs.
+    pub fn include(mut self, id: i32) -> Self {
+        self.include.get_or_insert(vec![]).push(id);
+        self
+    }
+    /// Order sort attribute ascending or descending. Options: asc and desc. Default is asc.
+    pub fn order(mut self, order: SortOrder) -> Self {
+        let _ = self.order.insert(order);
+        self
+    }
+    /// Sort collection by object attribute. Options: id, include, name and registered_date. Default is name.
+    pub fn orderby(mut self, orderby: CustomerSortBy) -> Self {
+        let _ = self.orderby.insert(orderby);
+        self
+    }
+    /// Limit result set to resources with a specific email.
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        let _ = self.email.insert(email.into());
+        self
+    }
+    /// Limit result set to resources with a specific role. Default is customer.
+    pub fn role(mut self, role: Role) -> Self {
+        let _ = self.role.insert(role);
+        self
+    }
+}
+/// Customer attribute to sort collection by.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerSortBy {
+    Id,
+    Include,
+    #[default]
+    Name,
+    RegisteredDate,
+}
@@ -1,13 +1,19 @@
-use anyhow::{anyhow, Result};
+use std::collections::{BTreeMap, BTreeSet};
+
 use serde::Serialize;
 use tokio::task::JoinSet;
 use url::Url;
 
-use crate::{ApiClient, BatchObject};
+use crate::{ApiClient, BatchObject, Error, PageError, PartialList, Result};
 
-use super::Entity;
+use super::{
+    decode,
+    query::{query_pairs, Query},
+    Entity, EntityId,
+};
 
 const BATCH: &str = "batch";
+const PER_PAGE: i32 = 50;
 
 impl ApiClient {
     /// This API lets you retrieve and view a specific entity by ID.
@@ -30,43 +36,16 @@ impl ApiClient {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn retrieve<T: Entity>(&self, entity_id: i32) -> Result<T> {
+    pub async fn retrieve<T: Entity>(&self, entity_id: impl Into<T::Id>) -> Result<T> {
         let uri = self
             .base_url
             .join(&T::endpoint())?
-            .join(&entity_id.to_string())?;
-        self.get_request_with_tries(uri, 3).await
+            .join(&entity_id.into().path_segment())?;
+        self.get_request(uri).await
     }
 
-    async fn get_request_with_tries<T: Entity>(&self, uri: Url, tries: i32) -> Result<T> {
-        for i in 1..tries {
-            tracing::debug!("Connecting {uri}, try {i}");
-            match self
-                .client
-                .get(uri.clone())
-                .basic_auth(self.ck(), Some(self.cs()))
-                .send()
-                .await?
-                .json::<T>()
-                .await
-            {
-                Ok(r) => {
-                    return Ok(r);
-                }
-                Err(e) => {
-                    tracing::error!(
-                        "Failed to connect to {uri} with error: {e}\n{} tries left",
-                        tries - i
-                    );
-                    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
-                    continue;
-                }
-            }
-        }
-        Err(anyhow!(
-            "Error retrieving entity with uri: {}",
-            uri.to_string()
-        ))
+    async fn get_request<T: Entity>(&self, uri: Url) -> Result<T> {
+        self.send_with_retry(self.client.get(uri)).await
     }
     /// This API helps you to view all entities of type T.
     ///
@@ -88,43 +67,143 @@ impl ApiClient {
     /// }
     /// ```
     pub async fn list_all<T: Entity>(&self) -> Result<Vec<T>> {
+        self.list_all_partial::<T>().await?.into_result()
+    }
+    /// This API helps you to view all entities of type T, keeping the pages that were fetched
+    /// even if some other pages failed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use rust_woocommerce::{Product, ApiClient, Config};
+    /// use tracing::{info, warn};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     tracing_subscriber::fmt::init();
+    ///     let config = Config::new("woo.toml")?;
+    ///     let client = ApiClient::new(&config)?;
+    ///     let products = client.list_all_partial::<Product>().await?;
+    ///     for e in &products.errors {
+    ///         warn!("Missing {e}");
+    ///     }
+    ///     info!("Got {} products", products.items.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn list_all_partial<T: Entity>(&self) -> Result<PartialList<T>> {
         let uri = self.base_url.join(&T::endpoint())?;
-        let mut result = Vec::new();
-        let mut set = JoinSet::new();
-        let total_response = self
-            .client
-            .get(uri.clone())
-            .basic_auth(&self.ck(), Some(self.cs()))
-            .send()
+        self.list_pages(uri, Vec::new()).await
+    }
+    /// This API helps you to view entities of type T matching the query, fetching every page of results.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use chrono::{Duration, Utc};
+    /// use rust_woocommerce::{ApiClient, Config, Order, OrderStatus};
+    /// use tracing::info;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     tracing_subscriber::fmt::init();
+    ///     let config = Config::new("woo.toml")?;
+    ///     let client = ApiClient::new(&config)?;
+    ///     let since = (Utc::now() - Duration::days(1)).naive_utc();
+    ///     let query = Order::query()
+    ///         .after(since)
+    ///         .dates_are_gmt()
+    ///         .status(OrderStatus::Processing);
+    ///     let orders = client.list::<Order>(query).await?;
+    ///     info!("Got {} orders since yesterday", orders.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn list<T: Entity>(&self, query: impl Query<T>) -> Result<Vec<T>> {
+        let uri = self.base_url.join(&T::endpoint())?;
+        self.list_pages(uri, query_pairs(&query)?)
+            .await?
+            .into_result()
+    }
+
+    async fn list_pages<T: Entity>(
+        &self,
+        uri: Url,
+        query: Vec<(String, String)>,
+    ) -> Result<PartialList<T>> {
+        let first_page = self
+            .execute_with_retry(
+                self.client
+                    .get(uri.clone())
+                    .query(&query)
+                    .query(&[("page", 1), ("per_page", PER_PAGE)]),
+            )
             .await?;
-        let total = total_response
+        let total_pages = first_page
             .headers()
-            .get("X-WP-Total")
-            .and_then(|h| h.to_str().ok().map(|p| p.parse::<i32>().ok()))
-            .flatten()
-            .unwrap_or_default();
-        let per_page = 50;
-        let total_pages = total / per_page + 1;
-        for page in 1..=total_pages {
-            let client = self.client();
-            let ck = self.ck();
-            let cs = Some(self.cs());
+            .get("X-WP-TotalPages")
+            .and_then(|h| h.to_str().ok())
+            .and_then(|p| p.parse::<i32>().ok())
+            .unwrap_or(1);
+        let mut pages = BTreeMap::new();
+        let mut errors = Vec::new();
+        match decode::<Vec<T>>(first_page, self.strict).await {
+            Ok(v) => {
+                pages.insert(1, v);
+            }
+            Err(error) => errors.push(PageError { page: 1, error }),
+        }
+        let mut pending = (2..=total_pages).collect::<BTreeSet<_>>();
+        let mut set = JoinSet::new();
+        for &page in &pending {
+            let client = self.clone();
             let url = uri.clone();
+            let query = query.clone();
             set.spawn(async move {
-                client
-                    .get(url)
-                    .query(&[("page", page), ("per_page", per_page)])
-                    .basic_auth(ck, cs)
-                    .send()
-                    .await?
-                    .json::<Vec<T>>()
-                    .await
+                let result = client
+                    .send_with_retry::<Vec<T>>(
+                        client
+                            .client
+                            .get(url)
+                            .query(&query)
+                            .query(&[("page", page), ("per_page", PER_PAGE)]),
+                    )
+                    .await;
+                (page, result)
             });
         }
-        while let Some(Ok(Ok(v))) = set.join_next().await {
-            result.extend(v)
+        let mut task_errors = Vec::new();
+        while let Some(joined) = set.join_next().await {
+            match joined {
+                Ok((page, result)) => {
+                    pending.remove(&page);
+                    match result {
+                        Ok(v) => {
+                            pages.insert(page, v);
+                        }
+                        Err(error) => errors.push(PageError { page, error }),
+                    }
+                }
+                Err(e) => task_errors.push(e.to_string()),
+            }
+        }
+        // Tasks that failed to join never reported their page, so the remaining pages are theirs
+        for (page, e) in pending.into_iter().zip(task_errors) {
+            errors.push(PageError {
+                page,
+                error: Error::Task(e),
+            });
+        }
+        errors.sort_by_key(|e| e.page);
+        for e in &errors {
+            tracing::error!("Failed to fetch {uri}, {e}");
         }
-        Ok(result)
+        Ok(PartialList {
+            items: pages.into_values().flatten().collect(),
+            errors,
+        })
     }
     /// This API helps you to create a new entity of type T.
     ///
@@ -166,44 +245,12 @@ impl ApiClient {
     /// ```
     pub async fn create<T: Entity>(&self, object: impl Serialize) -> Result<T> {
         let uri = self.base_url.join(&T::endpoint())?;
-        self.post_request_with_tries(&object, uri, 3).await
+        self.post_request(&object, uri).await
     }
 
-    async fn post_request_with_tries<T: Entity, O: Serialize + Sized>(
-        &self,
-        object: &O,
-        uri: Url,
-        tries: i32,
-    ) -> Result<T> {
-        for i in 1..tries {
-            tracing::debug!("Connecting {uri}, try {i}");
-            match self
-                .client
-                .post(uri.clone())
-                .basic_auth(self.ck(), Some(self.cs()))
-                .json(&object)
-                .send()
-                .await?
-                .json::<T>()
-                .await
-            {
-                Ok(r) => {
-                    return Ok(r);
-                }
-                Err(e) => {
-                    tracing::error!(
-                        "Failed to connect to {uri} with error: {e}\n{} tries left",
-                        tries - i
-                    );
-                    tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
-                    continue;
-                }
-            }
-        }
-        Err(anyhow!(
-            "Error creating entity with uri: {}",
-            uri.to_string()
-        ))
+    async fn post_request<T: Entity>(&self, object: &impl Serialize, uri: Url) -> Result<T> {
+        self.send_with_retry(self.client.post(uri).json(object))
+            .await
     }
     /// This API lets you make changes to entity.
     ///
@@ -228,47 +275,21 @@ impl ApiClient {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn update<T: Entity>(&self, entity_id: i32, object: impl Serialize) -> Result<T> {
+    pub async fn update<T: Entity>(
+        &self,
+        entity_id: impl Into<T::Id>,
+        object: impl Serialize,
+    ) -> Result<T> {
         let uri = self
             .base_url
             .join(&T::endpoint())?
-            .join(&entity_id.to_string())?;
-        self.put_request_with_tries(&object, uri, 3).await
+            .join(&entity_id.into().path_segment())?;
+        self.put_request(&object, uri).await
     }
 
-    async fn put_request_with_tries<T: Entity, O: Serialize + Sized>(
-        &self,
-        object: &O,
-        uri: Url,
-        tries: i32,
-    ) -> Result<T> {
-        for i in 1..tries {
-            tracing::debug!("Connecting {uri}, try {i}");
-            match self
-                .client
-                .put(uri.clone())
-                .basic_auth(self.ck(), Some(self.cs()))
-                .json(&object)
-                .send()
-                .await?
-                .json::<T>()
-                .await
-            {
-                Ok(r) => return Ok(r),
-                Err(e) => {
-                    tracing::error!(
-                        "Failed to connect to {uri} with error: {e}\n{} tries left",
-                        tries - i
-                    );
-                    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                    continue;
-                }
-            }
-        }
-        Err(anyhow!(
-            "Error updating entity with uri: {}",
-            uri.to_string()
-        ))
+    async fn put_request<T: Entity>(&self, object: &impl Serialize, uri: Url) -> Result<T> {
+        self.send_with_retry(self.client.put(uri).json(object))
+            .await
     }
     /// This API helps you delete a product.
     ///
@@ -289,44 +310,17 @@ impl ApiClient {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn delete<T: Entity>(&self, entity_id: i32) -> Result<T> {
+    pub async fn delete<T: Entity>(&self, entity_id: impl Into<T::Id>) -> Result<T> {
         let uri = self
             .base_url
             .join(&T::endpoint())?
-            .join(&entity_id.to_string())?;
-        self.delete_request_with_tries(uri, 3).await
+            .join(&entity_id.into().path_segment())?;
+        self.delete_request(uri).await
     }
 
-    async fn delete_request_with_tries<T: Entity>(&self, uri: Url, tries: i32) -> Result<T> {
-        for i in 1..tries {
-            tracing::debug!("Connecting {uri}, try {i}");
-            match self
-                .client
-                .delete(uri.clone())
-                .basic_auth(self.ck(), Some(self.cs()))
-                .query(&[("force", true)])
-                .send()
-                .await?
-                .json::<T>()
-                .await
-            {
-                Ok(r) => {
-                    return Ok(r);
-                }
-                Err(e) => {
-                    tracing::error!(
-                        "Failed to connect to {uri} with error: {e}\n{} tries left",
-                        tries - i
-                    );
-                    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                    continue;
-                }
-            }
-        }
-        Err(anyhow!(
-            "Error deleting entity with uri: {}",
-            uri.to_string()
-        ))
+    async fn delete_request<T: Entity>(&self, uri: Url) -> Result<T> {
+        self.send_with_retry(self.client.delete(uri).query(&[("force", true)]))
+            .await
     }
     /// This API helps you to batch create multiple entities.
     ///
@@ -370,33 +364,17 @@ impl ApiClient {
         &self,
         create_objects: Vec<O>,
     ) -> Result<Vec<T>> {
-        let mut result = Vec::new();
-        let mut set = JoinSet::new();
         let uri = self.base_url.join(&T::endpoint())?.join(BATCH)?;
         let batched = create_objects
             .chunks(100)
             .map(|c| BatchObject::builder().extend_create(c.to_vec()).build())
             .collect::<Vec<_>>();
-        for batch in batched {
-            let client = self.client();
-            let ck = self.ck();
-            let cs = Some(self.cs());
-            let url = uri.clone();
-            set.spawn(async move {
-                client
-                    .post(url)
-                    .basic_auth(ck, cs)
-                    .json(&batch)
-                    .send()
-                    .await?
-                    .json::<BatchObject<T>>()
-                    .await
-            });
-        }
-        while let Some(Ok(Ok(v))) = set.join_next().await {
-            result.extend(v.create)
-        }
-        Ok(result.into_iter().flatten().collect::<Vec<_>>())
+        let result = self.send_batches::<T, _>(uri, batched, &[]).await?;
+        Ok(result
+            .into_iter()
+            .flat_map(|v| v.create)
+            .flatten()
+            .collect())
     }
     /// This API helps you to batch update multiple entities.
     ///
@@ -415,8 +393,7 @@ impl ApiClient {
     ///     let client = ApiClient::new(&config)?;
     ///     let batch_update = Category::update()
     ///         .id(12345)
-    ///         .description("Some description")
-    ///         .build();
+    ///         .description("Some description");
     ///     let batch_updated: Vec<Category> = client.batch_update(vec![batch_update]).await?;
     ///     Ok(())
     /// }
@@ -425,33 +402,17 @@ impl ApiClient {
         &self,
         update_objects: Vec<O>,
     ) -> Result<Vec<T>> {
-        let mut result = Vec::new();
-        let mut set = JoinSet::new();
         let uri = self.base_url.join(&T::endpoint())?.join(BATCH)?;
         let batched = update_objects
             .chunks(100)
             .map(|c| BatchObject::builder().extend_update(c.to_vec()).build())
             .collect::<Vec<_>>();
-        for batch in batched {
-            let client = self.client();
-            let ck = self.ck();
-            let cs = Some(self.cs());
-            let url = uri.clone();
-            set.spawn(async move {
-                client
-                    .post(url)
-                    .basic_auth(ck, cs)
-                    .json(&batch)
-                    .send()
-                    .await?
-                    .json::<BatchObject<T>>()
-                    .await
-            });
-        }
-        while let Some(Ok(Ok(v))) = set.join_next().await {
-            result.extend(v.update)
-        }
-        Ok(result.into_iter().flatten().collect::<Vec<_>>())
+        let result = self.send_batches::<T, _>(uri, batched, &[]).await?;
+        Ok(result
+            .into_iter()
+            .flat_map(|v| v.update)
+            .flatten()
+            .collect())
     }
     /// This API helps you to batch delete multiple entities.
     ///
@@ -472,35 +433,20 @@ impl ApiClient {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn batch_delete<T: Entity>(&self, delete_objects: Vec<i32>) -> Result<Vec<T>> {
-        let mut result = Vec::new();
-        let mut set = JoinSet::new();
+    pub async fn batch_delete<T: Entity>(&self, delete_objects: Vec<T::Id>) -> Result<Vec<T>> {
         let uri = self.base_url.join(&T::endpoint())?.join(BATCH)?;
         let batched = delete_objects
             .chunks(100)
             .map(|c| BatchObject::builder().extend_delete(c.to_vec()).build())
             .collect::<Vec<_>>();
-        for batch in batched {
-            let client = self.client();
-            let ck = self.ck();
-            let cs = Some(self.cs());
-            let url = uri.clone();
-            set.spawn(async move {
-                client
-                    .post(url)
-                    .basic_auth(ck, cs)
-                    .query(&[("force", true)])
-                    .json(&batch)
-                    .send()
-                    .await?
-                    .json::<BatchObject<T>>()
-                    .await
-            });
-        }
-        while let Some(Ok(Ok(v))) = set.join_next().await {
-            result.extend(v.delete)
-        }
-        Ok(result.into_iter().flatten().collect::<Vec<_>>())
+        let result = self
+            .send_batches::<T, _>(uri, batched, &[("force", true)])
+            .await?;
+        Ok(result
+            .into_iter()
+            .flat_map(|v| v.delete)
+            .flatten()
+            .collect())
     }
     /// This API lets you retrieve and view a specific subentity by ID.
     ///
@@ -526,13 +472,13 @@ impl ApiClient {
     pub async fn retrieve_subentity<T: Entity>(
         &self,
         entity_id: i32,
-        subentity_id: i32,
+        subentity_id: impl Into<T::Id>,
     ) -> Result<T> {
         let uri = self
             .base_url
             .join(&T::child_endpoint(entity_id))?
-            .join(&subentity_id.to_string())?;
-        self.get_request_with_tries(uri, 3).await
+            .join(&subentity_id.into().path_segment())?;
+        self.get_request(uri).await
     }
     /// This API lets you view all subentities of entity.
     ///
@@ -559,33 +505,42 @@ impl ApiClient {
     /// }
     /// ```
     pub async fn list_all_subentities<T: Entity>(&self, entity_id: i32) -> Result<Vec<T>> {
+        self.list_all_subentities_partial::<T>(entity_id)
+            .await?
+            .into_result()
+    }
+    /// This API lets you view all subentities of entity, keeping the pages that were fetched
+    /// even if some other pages failed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use tracing::info;
+    ///
+    /// use rust_woocommerce::{ApiClient, Config, ProductVariation};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     tracing_subscriber::fmt::init();
+    ///     let config = Config::new("woo.toml")?;
+    ///     let client = ApiClient::new(&config)?;
+    ///     let variations = client
+    ///         .list_all_subentities_partial::<ProductVariation>(12345)
+    ///         .await?;
+    ///     info!(
+    ///         "Got {} variations, {} pages failed",
+    ///         variations.items.len(),
+    ///         variations.errors.len()
+    ///     );
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn list_all_subentities_partial<T: Entity>(
+        &self,
+        entity_id: i32,
+    ) -> Result<PartialList<T>> {
         let uri = self.base_url.join(&T::child_endpoint(entity_id))?;
-
-        for i in 1..3 {
-            tracing::debug!("Connecting {uri}, try {i}");
-            match self
-                .client
-                .get(uri.clone())
-                .basic_auth(self.ck(), Some(self.cs()))
-                .send()
-                .await?
-                .json::<Vec<T>>()
-                .await
-            {
-                Ok(r) => return Ok(r),
-                Err(e) => {
-                    tracing::error!(
-                        "Failed to connect to {uri} with error: {e}\n{} tries left",
-                        3 - i
-                    );
-                    tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
-                    continue;
-                }
-            }
-        }
-        Err(anyhow!(
-            "Error retrieving subentitity for entity with id: {entity_id}"
-        ))
+        self.list_pages(uri, Vec::new()).await
     }
     /// This API helps you create a new subentity.
     ///
@@ -613,7 +568,7 @@ impl ApiClient {
     ///     let created_variation: ProductVariation =
     ///         client.create_subentity(12345, variation).await?;
     ///     info!(
-    ///         "Variation {} created with price: {}",
+    ///         "Variation {} created with price: {:?}",
     ///         created_variation.sku, created_variation.price
     ///     );
     ///     Ok(())
@@ -625,7 +580,7 @@ impl ApiClient {
         object: impl Serialize,
     ) -> Result<T> {
         let uri = self.base_url.join(&T::child_endpoint(entity_id))?;
-        self.post_request_with_tries(&object, uri, 3).await
+        self.post_request(&object, uri).await
     }
     /// This API lets you make changes to subentity.
     ///
@@ -646,7 +601,7 @@ impl ApiClient {
     ///         .update_subentity(12345, 42, update)
     ///         .await?;
     ///     info!(
-    ///         "Variation {} updated with price: {}",
+    ///         "Variation {} updated with price: {:?}",
     ///         updated_variation.sku, updated_variation.price
     ///     );
     ///     Ok(())
@@ -655,14 +610,14 @@ impl ApiClient {
     pub async fn update_subentity<T: Entity>(
         &self,
         entity_id: i32,
-        subentity_id: i32,
+        subentity_id: impl Into<T::Id>,
         object: impl Serialize,
     ) -> Result<T> {
         let uri = self
             .base_url
             .join(&T::child_endpoint(entity_id))?
-            .join(&subentity_id.to_string())?;
-        self.put_request_with_tries(&object, uri, 3).await
+            .join(&subentity_id.into().path_segment())?;
+        self.put_request(&object, uri).await
     }
     /// This API helps you delete subentity.
     ///
@@ -688,13 +643,13 @@ impl ApiClient {
     pub async fn delete_subentity<T: Entity>(
         &self,
         entity_id: i32,
-        subentity_id: i32,
+        subentity_id: impl Into<T::Id>,
     ) -> Result<T> {
         let uri = self
             .base_url
             .join(&T::child_endpoint(entity_id))?
-            .join(&subentity_id.to_string())?;
-        self.delete_request_with_tries(uri, 3).await
+            .join(&subentity_id.into().path_segment())?;
+        self.delete_request(uri).await
     }
     /// This API helps you to batch create subentities.
     ///
@@ -731,8 +686,6 @@ impl ApiClient {
         entity_id: i32,
         create_objects: Vec<O>,
     ) -> Result<Vec<T>> {
-        let mut result = Vec::new();
-        let mut set = JoinSet::new();
         let uri = self
             .base_url
             .join(&T::child_endpoint(entity_id))?
@@ -741,26 +694,12 @@ impl ApiClient {
             .chunks(100)
             .map(|c| BatchObject::builder().extend_create(c.to_vec()).build())
             .collect::<Vec<_>>();
-        for batch in batched {
-            let client = self.client();
-            let ck = self.ck();
-            let cs = Some(self.cs());
-            let url = uri.clone();
-            set.spawn(async move {
-                client
-                    .post(url)
-                    .basic_auth(ck, cs)
-                    .json(&batch)
-                    .send()
-                    .await?
-                    .json::<BatchObject<T>>()
-                    .await
-            });
-        }
-        while let Some(Ok(Ok(v))) = set.join_next().await {
-            result.extend(v.create)
-        }
-        Ok(result.into_iter().flatten().collect::<Vec<_>>())
+        let result = self.send_batches::<T, _>(uri, batched, &[]).await?;
+        Ok(result
+            .into_iter()
+            .flat_map(|v| v.create)
+            .flatten()
+            .collect())
     }
     /// This API helps you to batch update subentities.
     ///
@@ -792,8 +731,6 @@ impl ApiClient {
         entity_id: i32,
         update_objects: Vec<O>,
     ) -> Result<Vec<T>> {
-        let mut result = Vec::new();
-        let mut set = JoinSet::new();
         let uri = self
             .base_url
             .join(&T::child_endpoint(entity_id))?
@@ -802,26 +739,12 @@ impl ApiClient {
             .chunks(100)
             .map(|c| BatchObject::builder().extend_update(c.to_vec()).build())
             .collect::<Vec<_>>();
-        for batch in batched {
-            let client = self.client();
-            let ck = self.ck();
-            let cs = Some(self.cs());
-            let url = uri.clone();
-            set.spawn(async move {
-                client
-                    .post(url)
-                    .basic_auth(ck, cs)
-                    .json(&batch)
-                    .send()
-                    .await?
-                    .json::<BatchObject<T>>()
-                    .await
-            });
-        }
-        while let Some(Ok(Ok(v))) = set.join_next().await {
-            result.extend(v.update)
-        }
-        Ok(result.into_iter().flatten().collect::<Vec<_>>())
+        let result = self.send_batches::<T, _>(uri, batched, &[]).await?;
+        Ok(result
+            .into_iter()
+            .flat_map(|v| v.update)
+            .flatten()
+            .collect())
     }
     /// This API helps you to batch delete subentities.
     ///
@@ -849,8 +772,6 @@ impl ApiClient {
         entity_id: i32,
         delete_objects: Vec<O>,
     ) -> Result<Vec<T>> {
-        let mut result = Vec::new();
-        let mut set = JoinSet::new();
         let uri = self
             .base_url
             .join(&T::child_endpoint(entity_id))?
@@ -859,25 +780,62 @@ impl ApiClient {
             .chunks(100)
             .map(|c| BatchObject::builder().extend_delete(c.to_vec()).build())
             .collect::<Vec<_>>();
-        for batch in batched {
-            let client = self.client();
-            let ck = self.ck();
-            let cs = Some(self.cs());
+        let result = self.send_batches::<T, _>(uri, batched, &[]).await?;
+        Ok(result
+            .into_iter()
+            .flat_map(|v| v.delete)
+            .flatten()
+            .collect())
+    }
+
+    /// Send batch chunks concurrently, failing with [`Error::Pages`] numbered by chunk if any of them failed
+    async fn send_batches<T: Entity, O: Serialize + Send + 'static>(
+        &self,
+        uri: Url,
+        batches: Vec<BatchObject<O>>,
+        query: &'static [(&'static str, bool)],
+    ) -> Result<Vec<BatchObject<T>>> {
+        let mut pending = (1..=batches.len() as i32).collect::<BTreeSet<_>>();
+        let mut set = JoinSet::new();
+        for (chunk, batch) in pending.clone().into_iter().zip(batches) {
+            let client = self.clone();
             let url = uri.clone();
             set.spawn(async move {
-                client
-                    .post(url)
-                    .basic_auth(ck, cs)
-                    .json(&batch)
-                    .send()
-                    .await?
-                    .json::<BatchObject<T>>()
-                    .await
+                let result = client
+                    .send_with_retry::<BatchObject<T>>(
+                        client.client.post(url).query(query).json(&batch),
+                    )
+                    .await;
+                (chunk, result)
+            });
+        }
+        let mut chunks = BTreeMap::new();
+        let mut errors = Vec::new();
+        let mut task_errors = Vec::new();
+        while let Some(joined) = set.join_next().await {
+            match joined {
+                Ok((chunk, result)) => {
+                    pending.remove(&chunk);
+                    match result {
+                        Ok(v) => {
+                            chunks.insert(chunk, v);
+                        }
+                        Err(error) => errors.push(PageError { page: chunk, error }),
+                    }
+                }
+                Err(e) => task_errors.push(e.to_string()),
+            }
+        }
+        for (page, e) in pending.into_iter().zip(task_errors) {
+            errors.push(PageError {
+                page,
+                error: Error::Task(e),
             });
         }
-        while let Some(Ok(Ok(v))) = set.join_next().await {
-            result.extend(v.delete)
+        if !errors.is_empty() {
+            errors.sort_by_key(|e| e.page);
+            return Err(Error::Pages(errors));
         }
-        Ok(result.into_iter().flatten().collect::<Vec<_>>())
+        Ok(chunks.into_values().collect())
     }
 }
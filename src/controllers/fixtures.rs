@@ -0,0 +1,276 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Error, Result};
+
+use super::transport::{build_response, header_pairs, Transport};
+
+/// Replaces secrets in recorded fixtures
+const REDACTED: &str = "[REDACTED]";
+
+/// Request and response pair stored in a fixture file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// HTTP method of the request.
+    pub method: String,
+    /// Path and query of the request without credentials, e.g. `/wp-json/wc/v3/products?page=1&per_page=50`.
+    pub path: String,
+    /// Request body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<Value>,
+    /// HTTP status of the response.
+    pub status: u16,
+    /// Response headers, without cookies.
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// Response body, kept as JSON when it is a JSON object or array so that fixtures stay readable.
+    pub body: Value,
+}
+impl Interaction {
+    /// Whether the interaction answers a request with this method, path and body
+    fn matches(&self, method: &str, path: &str, body: &Option<Value>) -> bool {
+        self.method == method && self.path == path && self.request_body == *body
+    }
+    fn to_response(&self) -> reqwest::Response {
+        let body = match &self.body {
+            Value::String(body) => body.clone(),
+            body => body.to_string(),
+        };
+        build_response(self.status, &self.headers, body)
+    }
+}
+/// Read interactions from a fixture file
+pub fn load_fixture(path: impl AsRef<Path>) -> Result<Vec<Interaction>> {
+    let payload = std::fs::read_to_string(path)?;
+    serde_json::from_str(&payload).map_err(|source| Error::Deserialize { source, payload })
+}
+/// Path and query of a request with credentials and redacted values removed
+fn request_path(url: &url::Url, redactions: &[String]) -> String {
+    let query: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(name, _)| {
+            !matches!(name.as_ref(), "consumer_key" | "consumer_secret")
+                && !name.starts_with("oauth_")
+        })
+        .map(|(name, value)| (name.into_owned(), redact(value.into_owned(), redactions)))
+        .collect();
+    let mut path = redact(url.path().to_owned(), redactions);
+    if !query.is_empty() {
+        // Values are redacted before encoding, so that secrets such as emails are found
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(query)
+            .finish();
+        path = format!("{path}?{query}");
+    }
+    path
+}
+fn redact(mut text: String, redactions: &[String]) -> String {
+    for secret in redactions.iter().filter(|s| !s.is_empty()) {
+        text = text.replace(secret.as_str(), REDACTED);
+    }
+    text
+}
+/// Body as JSON when it is an object or array, as text otherwise
+fn body_value(body: String) -> Value {
+    match serde_json::from_str::<Value>(&body) {
+        Ok(value @ (Value::Object(_) | Value::Array(_))) => value,
+        _ => Value::String(body),
+    }
+}
+fn request_body(request: &reqwest::Request, redactions: &[String]) -> Option<Value> {
+    let bytes = request.body()?.as_bytes()?;
+    let body = redact(String::from_utf8_lossy(bytes).into_owned(), redactions);
+    Some(body_value(body))
+}
+/// [`Transport`] that sends requests through another transport and writes every interaction to a fixture file.
+///
+/// Credentials sent as query parameters, including OAuth 1.0a signatures, are left out and request headers are not recorded.
+/// Values such as the store address or webhook secrets can be scrubbed from paths, headers and bodies with
+/// [`RecordingTransport::redact`]. The file is rewritten after every response, so it is complete even if the process stops,
+/// failing to write it is logged and does not fail the request.
+///
+/// # Example
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use rust_woocommerce::{ApiClient, Config, Order, RecordingTransport};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let config = Config::new("woo.toml")?;
+///     let recorder = RecordingTransport::new("tests/fixtures/orders.json").redact(&config.woo.host);
+///     let client = ApiClient::new(&config)?.with_transport(recorder);
+///     client.list_all::<Order>().await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RecordingTransport {
+    inner: Arc<dyn Transport>,
+    path: PathBuf,
+    redactions: Vec<String>,
+    interactions: Mutex<Vec<Interaction>>,
+    /// Held while the file is written so that an older list never replaces a newer one
+    writing: tokio::sync::Mutex<()>,
+}
+impl RecordingTransport {
+    /// Record to the given file, replacing what it contains, sending requests with a default reqwest client
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        RecordingTransport {
+            inner: Arc::new(reqwest::Client::new()),
+            path: path.into(),
+            redactions: Vec::new(),
+            interactions: Mutex::new(Vec::new()),
+            writing: tokio::sync::Mutex::new(()),
+        }
+    }
+    /// Send requests through this transport, e.g. a reqwest client with timeouts or a proxy.
+    pub fn inner(mut self, inner: impl Transport + 'static) -> Self {
+        self.inner = Arc::new(inner);
+        self
+    }
+    /// Replace a secret with `[REDACTED]` wherever it appears in paths, headers and bodies, may be called multiple times.
+    pub fn redact(mut self, secret: impl Into<String>) -> Self {
+        self.redactions.push(secret.into());
+        self
+    }
+    /// Interactions recorded so far
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.interactions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+    /// Write the interactions recorded so far on a blocking thread, failures are logged
+    async fn save(&self) {
+        let _writing = self.writing.lock().await;
+        let interactions = self.interactions();
+        let path = self.path.clone();
+        let written = tokio::task::spawn_blocking(move || write_fixture(&path, &interactions))
+            .await
+            .unwrap_or_else(|e| Err(std::io::Error::other(e)));
+        if let Err(e) = written {
+            tracing::warn!("Failed to write fixture {}: {e}", self.path.display());
+        }
+    }
+}
+fn write_fixture(path: &Path, interactions: &[Interaction]) -> std::io::Result<()> {
+    let payload = serde_json::to_vec_pretty(interactions)?;
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, payload)?;
+    std::fs::rename(&tmp, path)
+}
+impl Transport for RecordingTransport {
+    fn execute(&self, request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>> {
+        Box::pin(async move {
+            let method = request.method().to_string();
+            let path = request_path(request.url(), &self.redactions);
+            let request_body = request_body(&request, &self.redactions);
+            let response = self.inner.execute(request).await?;
+            let status = response.status().as_u16();
+            let headers = header_pairs(response.headers());
+            let body = response.text().await?;
+            let replayed = build_response(status, &headers, body.clone());
+            let interaction = Interaction {
+                method,
+                path,
+                request_body,
+                status,
+                headers: headers
+                    .into_iter()
+                    .map(|(name, value)| (name, redact(value, &self.redactions)))
+                    .collect(),
+                body: body_value(redact(body, &self.redactions)),
+            };
+            self.interactions
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(interaction);
+            self.save().await;
+            Ok(replayed)
+        })
+    }
+}
+/// [`Transport`] answering requests from recorded interactions without touching the network.
+///
+/// Requests are matched by method, path, query and body, ignoring the host and credentials, so a client configured for any
+/// store replays fixtures recorded with [`RecordingTransport`]. Every interaction is used once, in recorded order among
+/// identical requests, which replays retried failures as they happened. A request without a matching interaction fails.
+/// Values redacted while recording have to be redacted with [`ReplayTransport::redact`] too, so that requests containing
+/// them match.
+///
+/// # Example
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use rust_woocommerce::{ApiClient, Order, ReplayTransport};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let client = ApiClient::builder()
+///         .host("shop.example.com")
+///         .credentials("ck", "cs")
+///         .transport(ReplayTransport::load("tests/fixtures/orders.json")?)
+///         .build()?;
+///     let orders = client.list_all::<Order>().await?;
+///     assert!(!orders.is_empty());
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ReplayTransport {
+    interactions: Mutex<Vec<(Interaction, bool)>>,
+    redactions: Vec<String>,
+}
+impl ReplayTransport {
+    /// Replay the given interactions
+    pub fn new(interactions: Vec<Interaction>) -> Self {
+        ReplayTransport {
+            interactions: Mutex::new(interactions.into_iter().map(|i| (i, false)).collect()),
+            redactions: Vec::new(),
+        }
+    }
+    /// Replay the interactions of a fixture file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        load_fixture(path).map(Self::new)
+    }
+    /// Replace a secret with `[REDACTED]` in request paths and bodies before matching them, like [`RecordingTransport::redact`].
+    pub fn redact(mut self, secret: impl Into<String>) -> Self {
+        self.redactions.push(secret.into());
+        self
+    }
+    /// Interactions that were not replayed yet
+    pub fn unused(&self) -> Vec<Interaction> {
+        self.interactions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter(|(_, used)| !used)
+            .map(|(interaction, _)| interaction.clone())
+            .collect()
+    }
+}
+impl Transport for ReplayTransport {
+    fn execute(&self, request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>> {
+        let method = request.method().to_string();
+        let path = request_path(request.url(), &self.redactions);
+        let body = request_body(&request, &self.redactions);
+        let mut interactions = self.interactions.lock().unwrap_or_else(|e| e.into_inner());
+        let replayed = interactions
+            .iter_mut()
+            .find(|(interaction, used)| !used && interaction.matches(&method, &path, &body))
+            .map(|(interaction, used)| {
+                *used = true;
+                interaction.to_response()
+            })
+            .ok_or_else(|| Error::Fixture(format!("no recorded response for {method} {path}")));
+        Box::pin(futures::future::ready(replayed))
+    }
+}
@@ -0,0 +1,62 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+
+use crate::ApiClient;
+
+/// Limits shared by every clone of [`ApiClient`]
+#[derive(Clone, Default)]
+pub(crate) struct Limiter {
+    concurrency: Option<Arc<Semaphore>>,
+    rate: Option<Arc<RateLimiter>>,
+}
+struct RateLimiter {
+    interval: Duration,
+    next: Mutex<Instant>,
+}
+impl RateLimiter {
+    /// Reserve the next free slot and sleep until it comes
+    async fn wait(&self) {
+        let slot = {
+            let mut next = self.next.lock().unwrap_or_else(|e| e.into_inner());
+            let slot = (*next).max(Instant::now());
+            *next = slot + self.interval;
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
+    }
+}
+impl Limiter {
+    /// Wait until a request may be sent, the returned permit has to be held while it is in flight
+    pub(crate) async fn acquire(&self) -> Option<OwnedSemaphorePermit> {
+        let permit = match &self.concurrency {
+            Some(semaphore) => semaphore.clone().acquire_owned().await.ok(),
+            None => None,
+        };
+        if let Some(rate) = &self.rate {
+            rate.wait().await;
+        }
+        permit
+    }
+}
+impl ApiClient {
+    /// Limit the number of requests in flight at the same time.
+    ///
+    /// The limit is shared by every clone of the client made after this call.
+    pub fn with_concurrency_limit(mut self, max_in_flight: usize) -> Self {
+        self.limiter.concurrency = Some(Arc::new(Semaphore::new(max_in_flight.max(1))));
+        self
+    }
+    /// Limit the number of requests sent per second, spreading them evenly.
+    ///
+    /// The limit is shared by every clone of the client made after this call.
+    pub fn with_rate_limit(mut self, requests_per_second: u32) -> Self {
+        self.limiter.rate = Some(Arc::new(RateLimiter {
+            interval: Duration::from_secs(1) / requests_per_second.max(1),
+            next: Mutex::new(Instant::now()),
+        }));
+        self
+    }
+}
@@ -1,12 +1,19 @@
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-use anyhow::Result;
+use crate::{Config, Error, PageError, Result};
 
-use crate::Config;
+use self::{
+    auth::Auth, cache::ResponseCache, limiter::Limiter, retry::RetryPolicy, transport::Transport,
+};
+pub mod auth;
+pub mod builder;
+pub mod cache;
 pub mod coupons;
 pub mod customers;
 pub mod data;
 pub mod entities;
+pub mod fixtures;
+mod limiter;
 pub mod order_notes;
 pub mod orders;
 pub mod payment_gateways;
@@ -18,21 +25,79 @@ pub mod product_shipping_classes;
 pub mod product_tags;
 pub mod product_variations;
 pub mod products;
+pub mod query;
 pub mod refunds;
 pub mod reports;
+pub mod retry;
 pub mod settings;
 pub mod shipping_methods;
 pub mod shipping_zone_locations;
 pub mod shipping_zone_methods;
 pub mod shipping_zones;
+pub mod store;
+pub mod stream;
+pub mod sync;
 pub mod tax_classes;
 pub mod tax_rates;
+pub mod transport;
+#[cfg(feature = "axum")]
+pub mod webhook_axum;
+pub mod webhook_receiver;
 pub mod webhooks;
 
 pub trait Entity: Serialize + for<'de> Deserialize<'de> + Clone + Send + 'static {
+    /// Type of the identifier used in the entity URL.
+    type Id: EntityId;
     fn endpoint() -> String;
     fn child_endpoint(parent_id: i32) -> String;
 }
+/// Identifier of an [`Entity`], numeric for most resources and a slug for e.g. payment gateways and settings
+pub trait EntityId:
+    std::fmt::Display + Serialize + DeserializeOwned + Clone + Send + Sync + 'static
+{
+    /// Identifier escaped for use as a single URL path segment
+    fn path_segment(&self) -> String {
+        percent_encoding::utf8_percent_encode(&self.to_string(), PATH_SEGMENT).to_string()
+    }
+}
+impl EntityId for i32 {}
+impl EntityId for i64 {}
+impl EntityId for String {}
+/// Characters escaped in a URL path segment
+const PATH_SEGMENT: &percent_encoding::AsciiSet = &percent_encoding::CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+/// Items of a paginated listing together with the pages that failed
+#[derive(Debug)]
+pub struct PartialList<T> {
+    /// Items from all successfully fetched pages, in page order.
+    pub items: Vec<T>,
+    /// Errors of the pages that could not be fetched.
+    pub errors: Vec<PageError>,
+}
+impl<T> PartialList<T> {
+    /// Returns true if every page was fetched
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+    /// Convert into the list of items, failing with [`Error::Pages`] if any page is missing
+    pub fn into_result(self) -> Result<Vec<T>> {
+        if self.errors.is_empty() {
+            Ok(self.items)
+        } else {
+            Err(Error::Pages(self.errors))
+        }
+    }
+}
 /// Struct representing an API client
 #[derive(Clone)]
 pub struct ApiClient {
@@ -40,6 +105,12 @@ pub struct ApiClient {
     cs: String,
     base_url: url::Url,
     client: reqwest::Client,
+    transport: std::sync::Arc<dyn Transport>,
+    retry_policy: RetryPolicy,
+    limiter: Limiter,
+    auth: Auth,
+    cache: Option<ResponseCache>,
+    strict: bool,
 }
 
 impl ApiClient {
@@ -53,41 +124,14 @@ impl ApiClient {
     ///
     /// A Result containing the ApiClient instance if successful, or an error
     pub fn new(config: &Config) -> Result<Self> {
-        let ck = config.woo.ck.to_owned();
-        let cs = config.woo.cs.to_owned();
-        let client = reqwest::Client::builder().gzip(true).build()?;
-        let base_url = match url::Url::parse(&config.woo.host) {
-            Ok(url) => url.join("/wp-json/wc/v3/")?,
-            Err(_) => {
-                let raw_url = format!("https://{}", config.woo.host);
-                let url = url::Url::parse(&raw_url)?;
-                url.join("/wp-json/wc/v3/")?
-            }
-        };
-        Ok(Self {
-            ck,
-            cs,
-            base_url,
-            client,
-        })
+        Self::init(&config.woo.host, &config.woo.ck, &config.woo.cs)
     }
-    /// Create a new ApiClient instance using environment variables
+    /// Create a new ApiClient instance using host and credentials
     pub fn init(host: impl AsRef<str>, ck: impl AsRef<str>, cs: impl AsRef<str>) -> Result<Self> {
-        let client = reqwest::Client::builder().gzip(true).build()?;
-        let base_url = match url::Url::parse(host.as_ref()) {
-            Ok(url) => url.join("/wp-json/wc/v3/")?,
-            Err(_) => {
-                let raw_url = format!("https://{url}", url = host.as_ref());
-                let url = url::Url::parse(&raw_url)?;
-                url.join("/wp-json/wc/v3/")?
-            }
-        };
-        Ok(Self {
-            client,
-            base_url,
-            ck: ck.as_ref().into(),
-            cs: cs.as_ref().into(),
-        })
+        Self::builder()
+            .host(host.as_ref())
+            .credentials(ck.as_ref(), cs.as_ref())
+            .build()
     }
     /// Create a new ApiClient instance using environment variables
     ///
@@ -95,18 +139,10 @@ impl ApiClient {
     ///
     /// A Result containing the ApiClient instance if successful, or an error
     pub fn from_env() -> Result<Self> {
-        let ck = std::env::var("WOO_CK")?;
-        let cs = std::env::var("WOO_CS")?;
-        let base_url_raw = std::env::var("BASE_URL")?;
-        let base_url = url::Url::parse(&format!("{base_url_raw}/wp-json/wc/v3/"))?;
-        let client = reqwest::Client::builder().gzip(true).build()?;
-
-        Ok(Self {
-            ck,
-            cs,
-            base_url,
-            client,
-        })
+        Self::builder()
+            .host(env_var("BASE_URL")?)
+            .credentials(env_var("WOO_CK")?, env_var("WOO_CS")?)
+            .build()
     }
     /// Get the Consumer Key
     pub fn ck(&self) -> String {
@@ -125,3 +161,49 @@ impl ApiClient {
         self.base_url.to_string()
     }
 }
+fn env_var(key: &str) -> Result<String> {
+    std::env::var(key).map_err(|e| Error::Config(format!("{key}: {e}")))
+}
+/// Date as the `YEAR-MONTH-DAYTHOUR:MINUTE:SECOND` GMT string WooCommerce expects
+pub(crate) fn gmt_string<Tz: chrono::TimeZone>(date: chrono::DateTime<Tz>) -> String {
+    date.with_timezone(&chrono::Utc)
+        .naive_utc()
+        .format("%Y-%m-%dT%H:%M:%S")
+        .to_string()
+}
+/// Turn any non-success response status into [`Error`]
+pub(crate) async fn check(response: reqwest::Response) -> Result<reqwest::Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let retry_after = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|h| h.to_str().ok())
+        .and_then(parse_retry_after);
+    let body = response.text().await?;
+    Err(Error::from_status(status, retry_after, &body))
+}
+/// Read `Retry-After`, either a number of seconds or an HTTP date
+fn parse_retry_after(value: &str) -> Option<std::time::Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(std::time::Duration::from_secs(seconds));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.to_utc() - chrono::Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+/// Read response body and deserialize it, keeping the raw payload on failure
+pub(crate) async fn decode<R: DeserializeOwned>(
+    response: reqwest::Response,
+    strict: bool,
+) -> Result<R> {
+    let payload = response.text().await?;
+    crate::models::strict::with_strict(strict, || serde_json::from_str(&payload))
+        .map_err(|source| Error::Deserialize { source, payload })
+}
@@ -1,7 +1,10 @@
-use crate::{Billing, CurrencyISO, MetaData, OrderStatus, Shipping, TaxStatus};
+use crate::{Billing, CurrencyISO, MetaData, Order, OrderStatus, Shipping, TaxStatus};
 use anyhow::{anyhow, Result};
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+
+use super::query::{comma_separated, Query, SortOrder};
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateOrder {
@@ -648,3 +651,124 @@ impl UpdateOrderBuilder {
         }
     }
 }
+/// Filters for listing orders.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct OrderQuery {
+    search: Option<String>,
+    after: Option<NaiveDateTime>,
+    before: Option<NaiveDateTime>,
+    modified_after: Option<NaiveDateTime>,
+    modified_before: Option<NaiveDateTime>,
+    dates_are_gmt: Option<bool>,
+    #[serde(serialize_with = "comma_separated")]
+    exclude: Option<Vec<i32>>,
+    #[serde(serialize_with = "comma_separated")]
+    include: Option<Vec<i32>>,
+    order: Option<SortOrder>,
+    orderby: Option<OrderSortBy>,
+    #[serde(serialize_with = "comma_separated")]
+    parent: Option<Vec<i32>>,
+    #[serde(serialize_with = "comma_separated")]
+    parent_exclude: Option<Vec<i32>>,
+    #[serde(serialize_with = "comma_separated")]
+    status: Option<Vec<OrderStatus>>,
+    customer: Option<i32>,
+    product: Option<i32>,
+    dp: Option<i32>,
+}
+impl Query<Order> for OrderQuery {}
+impl OrderQuery {
+    /// Limit results to those matching a string.
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        let _ = self.search.insert(search.into());
+        self
+    }
+    /// Limit response to resources published after a given ISO8601 compliant date.
+    pub fn after(mut self, after: NaiveDateTime) -> Self {
+        let _ = self.after.insert(after);
+        self
+    }
+    /// Limit response to resources published before a given ISO8601 compliant date.
+    pub fn before(mut self, before: NaiveDateTime) -> Self {
+        let _ = self.before.insert(before);
+        self
+    }
+    /// Limit response to resources modified after a given ISO8601 compliant date.
+    pub fn modified_after(mut self, modified_after: NaiveDateTime) -> Self {
+        let _ = self.modified_after.insert(modified_after);
+        self
+    }
+    /// Limit response to resources modified before a given ISO8601 compliant date.
+    pub fn modified_before(mut self, modified_before: NaiveDateTime) -> Self {
+        let _ = self.modified_before.insert(modified_before);
+        self
+    }
+    /// Whether to consider GMT post dates when limiting response by published or modified date.
+    pub fn dates_are_gmt(mut self) -> Self {
+        let _ = self.dates_are_gmt.insert(true);
+        self
+    }
+    /// Ensure result set excludes specific IDs.
+    pub fn exclude(mut self, id: i32) -> Self {
+        self.exclude.get_or_insert(vec![]).push(id);
+        self
+    }
+    /// Limit result set to specific ids.
+    pub fn include(mut self, id: i32) -> Self {
+        self.include.get_or_insert(vec![]).push(id);
+        self
+    }
+    /// Order sort attribute ascending or descending. Options: asc and desc. Default is desc.
+    pub fn order(mut self, order: SortOrder) -> Self {
+        let _ = self.order.insert(order);
+        self
+    }
+    /// Sort collection by object attribute. Options: date, modified, id, include, title and slug. Default is date.
+    pub fn orderby(mut self, orderby: OrderSortBy) -> Self {
+        let _ = self.orderby.insert(orderby);
+        self
+    }
+    /// Limit result set to those of particular parent IDs.
+    pub fn parent(mut self, parent_id: i32) -> Self {
+        self.parent.get_or_insert(vec![]).push(parent_id);
+        self
+    }
+    /// Limit result set to all items except those of a particular parent ID.
+    pub fn parent_exclude(mut self, parent_id: i32) -> Self {
+        self.parent_exclude.get_or_insert(vec![]).push(parent_id);
+        self
+    }
+    /// Limit result set to orders assigned a specific status. Can be called several times.
+    pub fn status(mut self, status: OrderStatus) -> Self {
+        self.status.get_or_insert(vec![]).push(status);
+        self
+    }
+    /// Limit result set to orders assigned a specific customer.
+    pub fn customer(mut self, customer_id: i32) -> Self {
+        let _ = self.customer.insert(customer_id);
+        self
+    }
+    /// Limit result set to orders assigned a specific product.
+    pub fn product(mut self, product_id: i32) -> Self {
+        let _ = self.product.insert(product_id);
+        self
+    }
+    /// Number of decimal points to use in each resource. Default is 2.
+    pub fn dp(mut self, dp: i32) -> Self {
+        let _ = self.dp.insert(dp);
+        self
+    }
+}
+/// Order attribute to sort collection by.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum OrderSortBy {
+    #[default]
+    Date,
+    Modified,
+    Id,
+    Include,
+    Title,
+    Slug,
+}
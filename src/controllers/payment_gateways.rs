@@ -1,7 +1,65 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct PaymentGatewayUpdate {
+    /// Payment gateway title on checkout.
+    pub title: Option<String>,
+    /// Payment gateway description on checkout.
+    pub description: Option<String>,
+    /// Payment gateway sort order.
+    pub order: Option<i32>,
+    /// Payment gateway enabled status.
+    pub enabled: Option<bool>,
+    /// Payment gateway settings values keyed by setting ID.
+    pub settings: Option<BTreeMap<String, serde_json::Value>>,
+}
+#[derive(Debug, Clone, Default)]
+pub struct PaymentGatewayUpdateBuilder {
+    title: Option<String>,
+    description: Option<String>,
+    order: Option<i32>,
+    enabled: Option<bool>,
+    settings: Option<BTreeMap<String, serde_json::Value>>,
+}
+impl PaymentGatewayUpdateBuilder {
+    /// Payment gateway title on checkout.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        let _ = self.title.insert(title.into());
+        self
+    }
+    /// Payment gateway description on checkout.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    /// Payment gateway sort order.
+    pub fn order(mut self, order: i32) -> Self {
+        let _ = self.order.insert(order);
+        self
+    }
     /// Payment gateway enabled status.
-    pub enabled: bool,
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        let _ = self.enabled.insert(enabled);
+        self
+    }
+    /// Value of a payment gateway setting, e.g. `setting("instructions", "Pay within 3 days")`.
+    pub fn setting(mut self, id: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.settings
+            .get_or_insert_with(BTreeMap::new)
+            .insert(id.into(), value.into());
+        self
+    }
+    pub fn build(self) -> PaymentGatewayUpdate {
+        PaymentGatewayUpdate {
+            title: self.title,
+            description: self.description,
+            order: self.order,
+            enabled: self.enabled,
+            settings: self.settings,
+        }
+    }
 }
@@ -77,14 +77,7 @@ impl<I, E> ProductReviewCreateBuilder<I, E> {
     }
     /// Review rating (0 to 5).
     pub fn rating(mut self, rating: i32) -> Self {
-        let rating = if rating >= 5 {
-            5
-        } else if rating <= 0 {
-            0
-        } else {
-            rating
-        };
-        let _ = self.rating.insert(rating);
+        let _ = self.rating.insert(rating.clamp(0, 5));
         self
     }
     /// Shows if the reviewer bought the product or not.
@@ -178,14 +171,7 @@ impl ProductReviewUpdateBuilder {
     }
     /// Review rating (0 to 5).
     pub fn rating(mut self, rating: i32) -> Self {
-        let rating = if rating >= 5 {
-            5
-        } else if rating <= 0 {
-            0
-        } else {
-            rating
-        };
-        let _ = self.rating.insert(rating);
+        let _ = self.rating.insert(rating.clamp(0, 5));
         self
     }
     /// Shows if the reviewer bought the product or not.
@@ -1,10 +1,13 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, TimeZone};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::{BackordersStatus, Dimensions, MetaData, ProductStatus, StockStatus, TaxStatus};
 
-use super::products::{DefaultAttributeDTO, DownloadDTO, ImageDTO};
+use super::{
+    gmt_string,
+    products::{DefaultAttributeDTO, DownloadDTO, ImageDTO},
+};
 
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,7 +18,9 @@ pub struct ProductVariationModify {
     regular_price: Option<String>,
     sale_price: Option<String>,
     date_on_sale_from: Option<NaiveDateTime>,
+    date_on_sale_from_gmt: Option<String>,
     date_on_sale_to: Option<NaiveDateTime>,
+    date_on_sale_to_gmt: Option<String>,
     status: Option<ProductStatus>,
     #[serde(rename = "virtual")]
     is_virtual: Option<bool>,
@@ -45,7 +50,9 @@ pub struct ProductVariationModifyBuilder {
     regular_price: Option<String>,
     sale_price: Option<String>,
     date_on_sale_from: Option<NaiveDateTime>,
+    date_on_sale_from_gmt: Option<String>,
     date_on_sale_to: Option<NaiveDateTime>,
+    date_on_sale_to_gmt: Option<String>,
     status: Option<ProductStatus>,
     is_virtual: Option<bool>,
     downloadable: Option<bool>,
@@ -99,6 +106,13 @@ impl ProductVariationModifyBuilder {
             .and_hms_opt(0, 0, 0)
             .unwrap();
         let _ = self.date_on_sale_from.insert(dt);
+        self.date_on_sale_from_gmt = None;
+        self
+    }
+    /// Start date of sale price as an instant, sent as `date_on_sale_from_gmt` so the site's timezone does not shift it.
+    pub fn date_on_sale_from_utc<Tz: TimeZone>(&mut self, date: DateTime<Tz>) -> &mut Self {
+        let _ = self.date_on_sale_from_gmt.insert(gmt_string(date));
+        self.date_on_sale_from = None;
         self
     }
     /// End date of sale price, in the site's timezone.
@@ -108,6 +122,13 @@ impl ProductVariationModifyBuilder {
             .and_hms_opt(0, 0, 0)
             .unwrap();
         let _ = self.date_on_sale_to.insert(dt);
+        self.date_on_sale_to_gmt = None;
+        self
+    }
+    /// End date of sale price as an instant, sent as `date_on_sale_to_gmt` so the site's timezone does not shift it.
+    pub fn date_on_sale_to_utc<Tz: TimeZone>(&mut self, date: DateTime<Tz>) -> &mut Self {
+        let _ = self.date_on_sale_to_gmt.insert(gmt_string(date));
+        self.date_on_sale_to = None;
         self
     }
     /// Variation status. Options: draft, pending, private and publish. Default is publish.
@@ -243,7 +264,9 @@ impl ProductVariationModifyBuilder {
             regular_price: self.regular_price.to_owned(),
             sale_price: self.sale_price.to_owned(),
             date_on_sale_from: self.date_on_sale_from,
+            date_on_sale_from_gmt: self.date_on_sale_from_gmt.clone(),
             date_on_sale_to: self.date_on_sale_to,
+            date_on_sale_to_gmt: self.date_on_sale_to_gmt.clone(),
             status: self.status.to_owned(),
             is_virtual: self.is_virtual,
             downloadable: self.downloadable,
@@ -1,10 +1,15 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, TimeZone};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::{
-    BackordersStatus, CatalogVisibility, MetaData, ProductStatus, ProductType, StockStatus,
-    TaxStatus,
+    BackordersStatus, CatalogVisibility, MetaData, Product, ProductStatus, ProductType,
+    StockStatus, TaxStatus,
+};
+
+use super::{
+    gmt_string,
+    query::{comma_separated, Query, SortOrder},
 };
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,7 +29,9 @@ pub struct ProductModify {
     regular_price: Option<String>,
     sale_price: Option<String>,
     date_on_sale_from: Option<NaiveDateTime>,
+    date_on_sale_from_gmt: Option<String>,
     date_on_sale_to: Option<NaiveDateTime>,
+    date_on_sale_to_gmt: Option<String>,
     #[serde(rename = "virtual")]
     is_virtual: Option<bool>,
     downloadable: Option<bool>,
@@ -79,7 +86,9 @@ pub struct ProductModifyBuilder {
     regular_price: Option<String>,
     sale_price: Option<String>,
     date_on_sale_from: Option<NaiveDateTime>,
+    date_on_sale_from_gmt: Option<String>,
     date_on_sale_to: Option<NaiveDateTime>,
+    date_on_sale_to_gmt: Option<String>,
     is_virtual: Option<bool>,
     downloadable: Option<bool>,
     downloads: Option<Vec<DownloadDTO>>,
@@ -189,6 +198,13 @@ impl ProductModifyBuilder {
             .and_hms_opt(0, 0, 0)
             .unwrap();
         let _ = self.date_on_sale_from.insert(dt);
+        self.date_on_sale_from_gmt = None;
+        self
+    }
+    /// Start date of sale price as an instant, sent as `date_on_sale_from_gmt` so the site's timezone does not shift it.
+    pub fn date_on_sale_from_utc<Tz: TimeZone>(&mut self, date: DateTime<Tz>) -> &mut Self {
+        let _ = self.date_on_sale_from_gmt.insert(gmt_string(date));
+        self.date_on_sale_from = None;
         self
     }
     /// End date of sale price, in the site's timezone.
@@ -198,6 +214,13 @@ impl ProductModifyBuilder {
             .and_hms_opt(0, 0, 0)
             .unwrap();
         let _ = self.date_on_sale_to.insert(dt);
+        self.date_on_sale_to_gmt = None;
+        self
+    }
+    /// End date of sale price as an instant, sent as `date_on_sale_to_gmt` so the site's timezone does not shift it.
+    pub fn date_on_sale_to_utc<Tz: TimeZone>(&mut self, date: DateTime<Tz>) -> &mut Self {
+        let _ = self.date_on_sale_to_gmt.insert(gmt_string(date));
+        self.date_on_sale_to = None;
         self
     }
     /// If the product is virtual. Default is false.
@@ -405,7 +428,9 @@ impl ProductModifyBuilder {
             regular_price: self.regular_price.clone(),
             sale_price: self.sale_price.clone(),
             date_on_sale_from: self.date_on_sale_from,
+            date_on_sale_from_gmt: self.date_on_sale_from_gmt.clone(),
             date_on_sale_to: self.date_on_sale_to,
+            date_on_sale_to_gmt: self.date_on_sale_to_gmt.clone(),
             is_virtual: self.is_virtual,
             downloadable: self.downloadable,
             downloads: self.downloads.clone(),
@@ -585,3 +610,193 @@ pub struct DefaultAttributeDTO {
     pub name: String,
     pub option: String,
 }
+/// Filters for listing products.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ProductQuery {
+    search: Option<String>,
+    after: Option<NaiveDateTime>,
+    before: Option<NaiveDateTime>,
+    modified_after: Option<NaiveDateTime>,
+    modified_before: Option<NaiveDateTime>,
+    dates_are_gmt: Option<bool>,
+    #[serde(serialize_with = "comma_separated")]
+    exclude: Option<Vec<i32>>,
+    #[serde(serialize_with = "comma_separated")]
+    include: Option<Vec<i32>>,
+    order: Option<SortOrder>,
+    orderby: Option<ProductSortBy>,
+    #[serde(serialize_with = "comma_separated")]
+    parent: Option<Vec<i32>>,
+    #[serde(serialize_with = "comma_separated")]
+    parent_exclude: Option<Vec<i32>>,
+    slug: Option<String>,
+    status: Option<ProductStatus>,
+    #[serde(rename = "type")]
+    product_type: Option<ProductType>,
+    sku: Option<String>,
+    featured: Option<bool>,
+    category: Option<i32>,
+    tag: Option<i32>,
+    shipping_class: Option<i32>,
+    attribute: Option<String>,
+    attribute_term: Option<i32>,
+    tax_class: Option<String>,
+    on_sale: Option<bool>,
+    min_price: Option<String>,
+    max_price: Option<String>,
+    stock_status: Option<StockStatus>,
+}
+impl Query<Product> for ProductQuery {}
+impl ProductQuery {
+    /// Limit results to those matching a string.
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        let _ = self.search.insert(search.into());
+        self
+    }
+    /// Limit response to resources published after a given ISO8601 compliant date.
+    pub fn after(mut self, after: NaiveDateTime) -> Self {
+        let _ = self.after.insert(after);
+        self
+    }
+    /// Limit response to resources published before a given ISO8601 compliant date.
+    pub fn before(mut self, before: NaiveDateTime) -> Self {
+        let _ = self.before.insert(before);
+        self
+    }
+    /// Limit response to resources modified after a given ISO8601 compliant date.
+    pub fn modified_after(mut self, modified_after: NaiveDateTime) -> Self {
+        let _ = self.modified_after.insert(modified_after);
+        self
+    }
+    /// Limit response to resources modified before a given ISO8601 compliant date.
+    pub fn modified_before(mut self, modified_before: NaiveDateTime) -> Self {
+        let _ = self.modified_before.insert(modified_before);
+        self
+    }
+    /// Whether to consider GMT post dates when limiting response by published or modified date.
+    pub fn dates_are_gmt(mut self) -> Self {
+        let _ = self.dates_are_gmt.insert(true);
+        self
+    }
+    /// Ensure result set excludes specific IDs.
+    pub fn exclude(mut self, id: i32) -> Self {
+        self.exclude.get_or_insert(vec![]).push(id);
+        self
+    }
+    /// Limit result set to specific ids.
+    pub fn include(mut self, id: i32) -> Self {
+        self.include.get_or_insert(vec![]).push(id);
+        self
+    }
+    /// Order sort attribute ascending or descending. Options: asc and desc. Default is desc.
+    pub fn order(mut self, order: SortOrder) -> Self {
+        let _ = self.order.insert(order);
+        self
+    }
+    /// Sort collection by object attribute. Options: date, modified, id, include, title, slug, price, popularity and rating. Default is date.
+    pub fn orderby(mut self, orderby: ProductSortBy) -> Self {
+        let _ = self.orderby.insert(orderby);
+        self
+    }
+    /// Limit result set to those of particular parent IDs.
+    pub fn parent(mut self, parent_id: i32) -> Self {
+        self.parent.get_or_insert(vec![]).push(parent_id);
+        self
+    }
+    /// Limit result set to all items except those of a particular parent ID.
+    pub fn parent_exclude(mut self, parent_id: i32) -> Self {
+        self.parent_exclude.get_or_insert(vec![]).push(parent_id);
+        self
+    }
+    /// Limit result set to products with a specific slug.
+    pub fn slug(mut self, slug: impl Into<String>) -> Self {
+        let _ = self.slug.insert(slug.into());
+        self
+    }
+    /// Limit result set to products assigned a specific status.
+    pub fn status(mut self, status: ProductStatus) -> Self {
+        let _ = self.status.insert(status);
+        self
+    }
+    /// Limit result set to products assigned a specific type.
+    pub fn product_type(mut self, product_type: ProductType) -> Self {
+        let _ = self.product_type.insert(product_type);
+        self
+    }
+    /// Limit result set to products with specific SKU(s). Use commas to separate.
+    pub fn sku(mut self, sku: impl Into<String>) -> Self {
+        let _ = self.sku.insert(sku.into());
+        self
+    }
+    /// Limit result set to featured products.
+    pub fn featured(mut self, featured: bool) -> Self {
+        let _ = self.featured.insert(featured);
+        self
+    }
+    /// Limit result set to products assigned a specific category ID.
+    pub fn category(mut self, category_id: i32) -> Self {
+        let _ = self.category.insert(category_id);
+        self
+    }
+    /// Limit result set to products assigned a specific tag ID.
+    pub fn tag(mut self, tag_id: i32) -> Self {
+        let _ = self.tag.insert(tag_id);
+        self
+    }
+    /// Limit result set to products assigned a specific shipping class ID.
+    pub fn shipping_class(mut self, shipping_class_id: i32) -> Self {
+        let _ = self.shipping_class.insert(shipping_class_id);
+        self
+    }
+    /// Limit result set to products with a specific attribute. Use the taxonomy name/attribute slug.
+    pub fn attribute(mut self, attribute: impl Into<String>) -> Self {
+        let _ = self.attribute.insert(attribute.into());
+        self
+    }
+    /// Limit result set to products with a specific attribute term ID (required an assigned attribute).
+    pub fn attribute_term(mut self, attribute_term_id: i32) -> Self {
+        let _ = self.attribute_term.insert(attribute_term_id);
+        self
+    }
+    /// Limit result set to products with a specific tax class.
+    pub fn tax_class(mut self, tax_class: impl Into<String>) -> Self {
+        let _ = self.tax_class.insert(tax_class.into());
+        self
+    }
+    /// Limit result set to products on sale.
+    pub fn on_sale(mut self, on_sale: bool) -> Self {
+        let _ = self.on_sale.insert(on_sale);
+        self
+    }
+    /// Limit result set to products based on a minimum price.
+    pub fn min_price(mut self, min_price: impl Into<String>) -> Self {
+        let _ = self.min_price.insert(min_price.into());
+        self
+    }
+    /// Limit result set to products based on a maximum price.
+    pub fn max_price(mut self, max_price: impl Into<String>) -> Self {
+        let _ = self.max_price.insert(max_price.into());
+        self
+    }
+    /// Limit result set to products with specified stock status. Options: instock, outofstock and onbackorder.
+    pub fn stock_status(mut self, stock_status: StockStatus) -> Self {
+        let _ = self.stock_status.insert(stock_status);
+        self
+    }
+}
+/// Product attribute to sort collection by.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProductSortBy {
+    #[default]
+    Date,
+    Modified,
+    Id,
+    Include,
+    Title,
+    Slug,
+    Price,
+    Popularity,
+    Rating,
+}
@@ -0,0 +1,57 @@
+use serde::{ser::Error as _, Deserialize, Serialize, Serializer};
+
+use crate::{Error, Result};
+
+use super::Entity;
+
+/// Query parameters accepted by the list endpoint of entity `T`
+pub trait Query<T: Entity>: Serialize {}
+
+/// Sort direction of list results.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+/// Serialize a list as comma separated values, the form WordPress accepts for array arguments
+pub(crate) fn comma_separated<S, T>(
+    values: &Option<Vec<T>>,
+    serializer: S,
+) -> core::result::Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let Some(values) = values else {
+        return serializer.serialize_none();
+    };
+    let values = values
+        .iter()
+        .map(
+            |v| match serde_json::to_value(v).map_err(S::Error::custom)? {
+                serde_json::Value::String(s) => Ok(s),
+                other => Ok(other.to_string()),
+            },
+        )
+        .collect::<core::result::Result<Vec<_>, S::Error>>()?;
+    serializer.serialize_str(&values.join(","))
+}
+/// Flatten query into key-value pairs, so it can be sent with every page request
+pub(crate) fn query_pairs(query: &impl Serialize) -> Result<Vec<(String, String)>> {
+    let value = serde_json::to_value(query).map_err(|e| Error::Config(e.to_string()))?;
+    let map = match value {
+        serde_json::Value::Object(map) => map,
+        serde_json::Value::Null => return Ok(Vec::new()),
+        _ => return Err(Error::Config(String::from("query must be a struct"))),
+    };
+    Ok(map
+        .into_iter()
+        .filter_map(|(k, v)| match v {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(s) => Some((k, s)),
+            other => Some((k, other.to_string())),
+        })
+        .collect())
+}
@@ -0,0 +1,157 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+use crate::{ApiClient, Error, Result};
+
+use super::{check, decode};
+
+/// Policy deciding when and how often failed requests are repeated.
+///
+/// Only transport errors, `429 Too Many Requests` and `5xx` responses are retried.
+/// POST requests are not idempotent, they are only retried after `429` or a failed connection, which the store never
+/// processed, unless [`RetryPolicy::retry_non_idempotent`] is set.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    retry_non_idempotent: bool,
+}
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(30),
+            retry_non_idempotent: false,
+        }
+    }
+}
+impl RetryPolicy {
+    /// Policy that sends every request exactly once.
+    pub fn none() -> Self {
+        Self::default().max_attempts(1)
+    }
+    /// Total number of attempts, including the first one. Default is 3.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+    /// Delay before the first retry, doubled on every following one. Default is 200ms.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+    /// Upper bound for the delay between attempts, including `Retry-After`. Default is 30s.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+    /// Retry POST requests (create and batch operations) too. Default is false.
+    pub fn retry_non_idempotent(mut self) -> Self {
+        self.retry_non_idempotent = true;
+        self
+    }
+    fn should_retry(&self, method: &Method, error: &Error) -> bool {
+        match error {
+            // The store did not process the request, repeating it is safe whatever the method
+            Error::RateLimited { .. } => true,
+            Error::Transport(e) if e.is_connect() => true,
+            _ if *method == Method::POST && !self.retry_non_idempotent => false,
+            Error::Transport(_) => true,
+            Error::Status { status, .. } => status.is_server_error(),
+            _ => false,
+        }
+    }
+    /// Delay before attempt `attempt + 1`, honoring `Retry-After` when the server sent one
+    fn delay(&self, attempt: u32, error: &Error) -> Duration {
+        if let Error::RateLimited {
+            retry_after: Some(retry_after),
+            ..
+        } = error
+        {
+            return (*retry_after).min(self.max_delay);
+        }
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+        // Equal jitter: half of the delay is fixed, the other half is random
+        let half = exponential / 2;
+        let random = RandomState::new().build_hasher().finish();
+        half + half.mul_f64((random % 1000) as f64 / 1000.0)
+    }
+}
+impl ApiClient {
+    /// Set the policy used to retry failed requests.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+    /// Get the retry policy
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy.clone()
+    }
+    /// Send request according to the retry policy and turn any non-success status into [`Error`]
+    pub(crate) async fn execute_with_retry(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response> {
+        let request = request.build()?;
+        match &self.cache {
+            Some(cache) => cache.execute(self, request).await,
+            None => self.execute_request(request).await,
+        }
+    }
+    /// Send a built request according to the retry policy, bypassing the response cache
+    pub(crate) async fn execute_request(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<reqwest::Response> {
+        let mut attempt = 1;
+        loop {
+            let Some(mut current) = request.try_clone() else {
+                let mut request = request;
+                self.authorize(&mut request)?;
+                let _permit = self.limiter.acquire().await;
+                return check(self.transport.execute(request).await?).await;
+            };
+            self.authorize(&mut current)?;
+            tracing::debug!("Connecting {}, try {attempt}", request.url());
+            let permit = self.limiter.acquire().await;
+            let result = match self.transport.execute(current).await {
+                Ok(response) => check(response).await,
+                Err(e) => Err(e),
+            };
+            drop(permit);
+            match result {
+                Ok(response) => return Ok(response),
+                Err(e)
+                    if attempt < self.retry_policy.max_attempts
+                        && self.retry_policy.should_retry(request.method(), &e) =>
+                {
+                    let delay = self.retry_policy.delay(attempt, &e);
+                    tracing::error!(
+                        "Failed to connect to {} with error: {e}\n{} tries left, retrying in {delay:?}",
+                        request.url(),
+                        self.retry_policy.max_attempts - attempt
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    /// Send request according to the retry policy and deserialize successful response
+    pub(crate) async fn send_with_retry<R: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<R> {
+        decode(self.execute_with_retry(request).await?, self.strict).await
+    }
+}
@@ -1,6 +1,282 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{ApiClient, BatchObject, Result, SettingOption};
+
+use super::EntityId;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SettingOptionUpdate {
     /// Setting value.
     pub value: serde_json::Value,
 }
+/// Item of a `settings/<group_id>/batch` request
+#[derive(Debug, Clone, Serialize)]
+struct SettingOptionBatchUpdate {
+    id: String,
+    value: Value,
+}
+/// Change of a single setting option needed to reach the desired configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingChange {
+    /// Setting option ID.
+    pub id: String,
+    /// Value currently set in the store.
+    pub current: Value,
+    /// Desired value.
+    pub desired: Value,
+}
+/// Difference between the options of a setting group and a desired configuration
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SettingsDiff {
+    /// Setting group ID.
+    pub group_id: String,
+    /// Options whose value differs from the desired one.
+    pub changes: Vec<SettingChange>,
+    /// Desired option IDs that do not exist in the group.
+    pub unknown: Vec<String>,
+}
+impl SettingsDiff {
+    /// Compare current options of a group with desired values keyed by option ID
+    pub fn new(
+        group_id: impl Into<String>,
+        current: &[SettingOption],
+        desired: &BTreeMap<String, Value>,
+    ) -> Self {
+        let current = current
+            .iter()
+            .map(|option| (option.id.as_str(), &option.value))
+            .collect::<BTreeMap<_, _>>();
+        let mut diff = SettingsDiff {
+            group_id: group_id.into(),
+            ..Default::default()
+        };
+        for (id, desired) in desired {
+            match current.get(id.as_str()) {
+                Some(current) if same_value(current, desired) => {}
+                Some(current) => diff.changes.push(SettingChange {
+                    id: id.clone(),
+                    current: (*current).clone(),
+                    desired: desired.clone(),
+                }),
+                None => diff.unknown.push(id.clone()),
+            }
+        }
+        diff
+    }
+    /// Returns true if the group already matches the desired configuration
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+/// WooCommerce returns every scalar setting as a string, so `10` and `"10"` are the same value
+pub(crate) fn same_value(current: &Value, desired: &Value) -> bool {
+    fn scalar(value: &Value) -> Option<String> {
+        match value {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            Value::Bool(true) => Some(String::from("yes")),
+            Value::Bool(false) => Some(String::from("no")),
+            _ => None,
+        }
+    }
+    if current == desired {
+        return true;
+    }
+    match (scalar(current), scalar(desired)) {
+        (Some(current), Some(desired)) => current == desired,
+        _ => false,
+    }
+}
+impl ApiClient {
+    fn settings_url(&self, group_id: &str) -> Result<url::Url> {
+        Ok(self
+            .base_url
+            .join("settings/")?
+            .join(&format!("{}/", group_id.to_owned().path_segment()))?)
+    }
+    /// This API lists all setting options of a group.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use rust_woocommerce::{ApiClient, Config};
+    /// use tracing::info;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     tracing_subscriber::fmt::init();
+    ///     let config = Config::new("woo.toml")?;
+    ///     let client = ApiClient::new(&config)?;
+    ///     let options = client.list_setting_options("general").await?;
+    ///     info!("General group has {} options", options.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn list_setting_options(
+        &self,
+        group_id: impl AsRef<str>,
+    ) -> Result<Vec<SettingOption>> {
+        let uri = self.settings_url(group_id.as_ref())?;
+        self.send_with_retry(self.client.get(uri)).await
+    }
+    /// This API retrieves a single setting option of a group.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use rust_woocommerce::{ApiClient, Config};
+    /// use tracing::info;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     tracing_subscriber::fmt::init();
+    ///     let config = Config::new("woo.toml")?;
+    ///     let client = ApiClient::new(&config)?;
+    ///     let option = client
+    ///         .retrieve_setting_option("general", "woocommerce_currency")
+    ///         .await?;
+    ///     info!("Store currency: {}", option.value);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn retrieve_setting_option(
+        &self,
+        group_id: impl AsRef<str>,
+        option_id: impl Into<String>,
+    ) -> Result<SettingOption> {
+        let uri = self
+            .settings_url(group_id.as_ref())?
+            .join(&option_id.into().path_segment())?;
+        self.send_with_retry(self.client.get(uri)).await
+    }
+    /// This API updates the value of a single setting option.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use rust_woocommerce::{ApiClient, Config};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let config = Config::new("woo.toml")?;
+    ///     let client = ApiClient::new(&config)?;
+    ///     client
+    ///         .update_setting_option("general", "woocommerce_currency", "EUR")
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn update_setting_option(
+        &self,
+        group_id: impl AsRef<str>,
+        option_id: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> Result<SettingOption> {
+        let uri = self
+            .settings_url(group_id.as_ref())?
+            .join(&option_id.into().path_segment())?;
+        let update = SettingOptionUpdate {
+            value: value.into(),
+        };
+        self.send_with_retry(self.client.put(uri).json(&update))
+            .await
+    }
+    /// This API updates multiple setting options of a group in one request.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use rust_woocommerce::{ApiClient, Config};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let config = Config::new("woo.toml")?;
+    ///     let client = ApiClient::new(&config)?;
+    ///     let updated = client
+    ///         .batch_update_setting_options(
+    ///             "general",
+    ///             [("woocommerce_currency", "EUR"), ("woocommerce_price_num_decimals", "2")],
+    ///         )
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn batch_update_setting_options<I, V>(
+        &self,
+        group_id: impl AsRef<str>,
+        updates: impl IntoIterator<Item = (I, V)>,
+    ) -> Result<Vec<SettingOption>>
+    where
+        I: Into<String>,
+        V: Into<Value>,
+    {
+        let uri = self.settings_url(group_id.as_ref())?.join("batch")?;
+        let updates = updates
+            .into_iter()
+            .map(|(id, value)| SettingOptionBatchUpdate {
+                id: id.into(),
+                value: value.into(),
+            })
+            .collect::<Vec<_>>();
+        let batch = BatchObject::builder().extend_update(updates).build();
+        let response = self
+            .send_with_retry::<BatchObject<SettingOption>>(self.client.post(uri).json(&batch))
+            .await?;
+        Ok(response.update.unwrap_or_default())
+    }
+    /// This API compares the options of a group with a desired configuration keyed by option ID.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::collections::BTreeMap;
+    /// use anyhow::Result;
+    /// use rust_woocommerce::{ApiClient, Config};
+    /// use tracing::info;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     tracing_subscriber::fmt::init();
+    ///     let config = Config::new("woo.toml")?;
+    ///     let client = ApiClient::new(&config)?;
+    ///     let desired = BTreeMap::from([(
+    ///         String::from("woocommerce_currency"),
+    ///         serde_json::json!("EUR"),
+    ///     )]);
+    ///     let diff = client.diff_settings("general", &desired).await?;
+    ///     for change in &diff.changes {
+    ///         info!("{}: {} -> {}", change.id, change.current, change.desired);
+    ///     }
+    ///     client.apply_settings(&diff).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn diff_settings(
+        &self,
+        group_id: impl AsRef<str>,
+        desired: &BTreeMap<String, Value>,
+    ) -> Result<SettingsDiff> {
+        let current = self.list_setting_options(group_id.as_ref()).await?;
+        Ok(SettingsDiff::new(group_id.as_ref(), &current, desired))
+    }
+    /// This API applies the changes of a diff with a single batch request, doing nothing if there are none.
+    pub async fn apply_settings(&self, diff: &SettingsDiff) -> Result<Vec<SettingOption>> {
+        if diff.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.batch_update_setting_options(
+            &diff.group_id,
+            diff.changes
+                .iter()
+                .map(|change| (change.id.clone(), change.desired.clone())),
+        )
+        .await
+    }
+}
@@ -1 +1,42 @@
+use crate::{ApiClient, Entity, Result, ShippingZoneLocation};
 
+impl ApiClient {
+    /// This API replaces all locations of a shipping zone, an empty list removes them all.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use rust_woocommerce::{ApiClient, Config, ShippingZoneLocation};
+    /// use tracing::info;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     tracing_subscriber::fmt::init();
+    ///     let config = Config::new("woo.toml")?;
+    ///     let client = ApiClient::new(&config)?;
+    ///     let locations = client
+    ///         .replace_shipping_zone_locations(
+    ///             5,
+    ///             vec![
+    ///                 ShippingZoneLocation::country("US"),
+    ///                 ShippingZoneLocation::postcode("902*"),
+    ///             ],
+    ///         )
+    ///         .await?;
+    ///     info!("Zone has {} locations", locations.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn replace_shipping_zone_locations(
+        &self,
+        zone_id: i32,
+        locations: Vec<ShippingZoneLocation>,
+    ) -> Result<Vec<ShippingZoneLocation>> {
+        let uri = self
+            .base_url
+            .join(&ShippingZoneLocation::child_endpoint(zone_id))?;
+        self.send_with_retry(self.client.put(uri).json(&locations))
+            .await
+    }
+}
@@ -1 +1,79 @@
+use std::collections::BTreeMap;
 
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShippingZoneMethodCreate {
+    method_id: String,
+    order: Option<i32>,
+    enabled: Option<bool>,
+    settings: Option<BTreeMap<String, String>>,
+}
+impl ShippingZoneMethodCreate {
+    /// Shipping method ID, e.g. flat_rate, free_shipping or local_pickup.
+    pub fn new(method_id: impl Into<String>) -> Self {
+        Self {
+            method_id: method_id.into(),
+            order: None,
+            enabled: None,
+            settings: None,
+        }
+    }
+    /// Shipping method sort order.
+    pub fn order(mut self, order: i32) -> Self {
+        let _ = self.order.insert(order);
+        self
+    }
+    /// Shipping method enabled status.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        let _ = self.enabled.insert(enabled);
+        self
+    }
+    /// Shipping method customer facing title.
+    pub fn title(self, title: impl Into<String>) -> Self {
+        self.setting("title", title)
+    }
+    /// Value of a shipping method setting, e.g. `setting("cost", "5")` for flat rate.
+    pub fn setting(mut self, id: impl Into<String>, value: impl Into<String>) -> Self {
+        self.settings
+            .get_or_insert_with(BTreeMap::new)
+            .insert(id.into(), value.into());
+        self
+    }
+}
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct ShippingZoneMethodUpdate {
+    order: Option<i32>,
+    enabled: Option<bool>,
+    settings: Option<BTreeMap<String, String>>,
+}
+impl ShippingZoneMethodUpdate {
+    /// Shipping method sort order.
+    pub fn order(mut self, order: i32) -> Self {
+        let _ = self.order.insert(order);
+        self
+    }
+    /// Shipping method enabled status.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        let _ = self.enabled.insert(enabled);
+        self
+    }
+    /// Shipping method customer facing title.
+    pub fn title(self, title: impl Into<String>) -> Self {
+        self.setting("title", title)
+    }
+    /// Value of a shipping method setting, e.g. `setting("cost", "5")` for flat rate.
+    pub fn setting(mut self, id: impl Into<String>, value: impl Into<String>) -> Self {
+        self.settings
+            .get_or_insert_with(BTreeMap::new)
+            .insert(id.into(), value.into());
+        self
+    }
+    /// Returns true if the update changes nothing
+    pub fn is_empty(&self) -> bool {
+        self.order.is_none() && self.enabled.is_none() && self.settings.is_none()
+    }
+}
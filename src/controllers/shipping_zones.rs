@@ -1,5 +1,11 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+
+use crate::{ApiClient, Error, Result, ShippingZone, ShippingZoneLocation, ShippingZoneMethod};
+
+use super::shipping_zone_methods::{ShippingZoneMethodCreate, ShippingZoneMethodUpdate};
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShippingZoneCreate {
@@ -44,3 +50,331 @@ impl ShippingZoneUpdate {
         self
     }
 }
+/// Desired state of a shipping method within a [`ShippingZoneSpec`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShippingMethodSpec {
+    /// Shipping method ID, e.g. flat_rate, free_shipping or local_pickup.
+    pub method_id: String,
+    /// Shipping method enabled status. Default is true.
+    #[serde(default = "enabled")]
+    pub enabled: bool,
+    /// Shipping method sort order, left as is when not set.
+    #[serde(default)]
+    pub order: Option<i32>,
+    /// Setting values keyed by setting ID, settings not listed here are left as is.
+    #[serde(default)]
+    pub settings: BTreeMap<String, String>,
+}
+fn enabled() -> bool {
+    true
+}
+impl ShippingMethodSpec {
+    /// Any shipping method
+    pub fn new(method_id: impl Into<String>) -> Self {
+        Self {
+            method_id: method_id.into(),
+            enabled: true,
+            order: None,
+            settings: BTreeMap::new(),
+        }
+    }
+    /// Flat rate with the given cost, which may be a formula like `10 + [qty] * 2`
+    pub fn flat_rate(cost: impl Into<String>) -> Self {
+        Self::new("flat_rate").setting("cost", cost)
+    }
+    /// Free shipping
+    pub fn free_shipping() -> Self {
+        Self::new("free_shipping")
+    }
+    /// Local pickup
+    pub fn local_pickup() -> Self {
+        Self::new("local_pickup")
+    }
+    /// Shipping method customer facing title.
+    pub fn title(self, title: impl Into<String>) -> Self {
+        self.setting("title", title)
+    }
+    /// Shipping method sort order.
+    pub fn order(mut self, order: i32) -> Self {
+        let _ = self.order.insert(order);
+        self
+    }
+    /// Keep the method but disable it.
+    pub fn disabled(mut self) -> Self {
+        self.enabled = false;
+        self
+    }
+    /// Value of a shipping method setting.
+    pub fn setting(mut self, id: impl Into<String>, value: impl Into<String>) -> Self {
+        self.settings.insert(id.into(), value.into());
+        self
+    }
+    fn create(&self) -> ShippingZoneMethodCreate {
+        let mut create = ShippingZoneMethodCreate::new(&self.method_id).enabled(self.enabled);
+        if let Some(order) = self.order {
+            create = create.order(order);
+        }
+        for (id, value) in &self.settings {
+            create = create.setting(id, value);
+        }
+        create
+    }
+    /// Changes needed to turn the existing method into this one
+    fn update(&self, current: &ShippingZoneMethod) -> ShippingZoneMethodUpdate {
+        let mut update = ShippingZoneMethodUpdate::default();
+        if current.enabled != self.enabled {
+            update = update.enabled(self.enabled);
+        }
+        if let Some(order) = self.order.filter(|order| *order != current.order) {
+            update = update.order(order);
+        }
+        for (id, value) in &self.settings {
+            if current.setting(id) != Some(value.as_str()) {
+                update = update.setting(id, value);
+            }
+        }
+        update
+    }
+}
+/// Desired state of a shipping zone, its locations and its methods.
+///
+/// Zones are matched by `id` when set, by name otherwise.
+/// Methods are matched by method ID in order, methods of the zone that are not declared are deleted.
+///
+/// ```toml
+/// name = "Europe"
+/// locations = [{ code = "EU", type = "continent" }]
+///
+/// [[methods]]
+/// method_id = "flat_rate"
+/// settings = { title = "Standard", cost = "5" }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShippingZoneSpec {
+    /// Shipping zone ID, needed for the "Locations not covered by your other zones" zone 0.
+    #[serde(default)]
+    pub id: Option<i32>,
+    /// Shipping zone name.
+    pub name: String,
+    /// Shipping zone order, left as is when not set.
+    #[serde(default)]
+    pub order: Option<i32>,
+    /// Shipping zone locations, replacing the current ones.
+    #[serde(default)]
+    pub locations: Vec<ShippingZoneLocation>,
+    /// Shipping zone methods.
+    #[serde(default)]
+    pub methods: Vec<ShippingMethodSpec>,
+}
+impl ShippingZoneSpec {
+    /// Zone matched by name
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            name: name.into(),
+            order: None,
+            locations: Vec::new(),
+            methods: Vec::new(),
+        }
+    }
+    /// The "Locations not covered by your other zones" zone, which has no locations of its own
+    pub fn rest_of_world() -> Self {
+        Self {
+            id: Some(0),
+            ..Self::new("Locations not covered by your other zones")
+        }
+    }
+    /// Shipping zone order.
+    pub fn order(mut self, order: i32) -> Self {
+        let _ = self.order.insert(order);
+        self
+    }
+    /// Add a location.
+    pub fn location(mut self, location: ShippingZoneLocation) -> Self {
+        self.locations.push(location);
+        self
+    }
+    /// Add a shipping method.
+    pub fn method(mut self, method: ShippingMethodSpec) -> Self {
+        self.methods.push(method);
+        self
+    }
+}
+/// Changes needed to bring a shipping zone to its [`ShippingZoneSpec`]
+#[derive(Debug, Clone)]
+pub struct ShippingZonePlan {
+    /// Shipping zone name from the spec.
+    pub name: String,
+    /// Existing zone, None if it has to be created.
+    pub zone: Option<ShippingZone>,
+    /// Zone to create.
+    pub create_zone: Option<ShippingZoneCreate>,
+    /// Changes of the zone name or order.
+    pub update_zone: Option<ShippingZoneUpdate>,
+    /// New locations, None if they already match.
+    pub locations: Option<Vec<ShippingZoneLocation>>,
+    /// Methods to add.
+    pub include_methods: Vec<ShippingZoneMethodCreate>,
+    /// Method changes keyed by instance ID.
+    pub update_methods: Vec<(i32, ShippingZoneMethodUpdate)>,
+    /// Instance IDs of methods to delete.
+    pub delete_methods: Vec<i32>,
+}
+impl ShippingZonePlan {
+    /// Returns true if the zone already matches its spec
+    pub fn is_empty(&self) -> bool {
+        self.create_zone.is_none()
+            && self.update_zone.is_none()
+            && self.locations.is_none()
+            && self.include_methods.is_empty()
+            && self.update_methods.is_empty()
+            && self.delete_methods.is_empty()
+    }
+}
+impl ApiClient {
+    /// This API compares a shipping zone with its spec and returns the changes needed, without applying them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use rust_woocommerce::{
+    ///     shipping_zones::{ShippingMethodSpec, ShippingZoneSpec},
+    ///     ApiClient, Config, ShippingZoneLocation,
+    /// };
+    /// use tracing::info;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     tracing_subscriber::fmt::init();
+    ///     let config = Config::new("woo.toml")?;
+    ///     let client = ApiClient::new(&config)?;
+    ///     let spec = ShippingZoneSpec::new("California")
+    ///         .location(ShippingZoneLocation::state("US:CA"))
+    ///         .method(ShippingMethodSpec::flat_rate("5").title("Standard"))
+    ///         .method(ShippingMethodSpec::free_shipping().setting("requires", "min_amount").setting("min_amount", "100"));
+    ///     let plan = client.plan_shipping_zone(&spec).await?;
+    ///     if !plan.is_empty() {
+    ///         let zone = client.apply_shipping_plan(&plan).await?;
+    ///         info!("Zone {} updated", zone.name);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn plan_shipping_zone(&self, spec: &ShippingZoneSpec) -> Result<ShippingZonePlan> {
+        let zones = self.list_all::<ShippingZone>().await?;
+        let zone = zones.into_iter().find(|zone| match spec.id {
+            Some(id) => zone.id == id,
+            None => zone.name == spec.name,
+        });
+        let mut plan = ShippingZonePlan {
+            name: spec.name.clone(),
+            zone: zone.clone(),
+            create_zone: None,
+            update_zone: None,
+            locations: None,
+            include_methods: Vec::new(),
+            update_methods: Vec::new(),
+            delete_methods: Vec::new(),
+        };
+        let Some(zone) = zone else {
+            if let Some(id) = spec.id {
+                return Err(Error::Config(format!("shipping zone {id} does not exist")));
+            }
+            let mut create = ShippingZoneCreate::new(&spec.name);
+            if let Some(order) = spec.order {
+                create = create.order(order);
+            }
+            plan.create_zone = Some(create);
+            if !spec.locations.is_empty() {
+                plan.locations = Some(spec.locations.clone());
+            }
+            plan.include_methods = spec.methods.iter().map(|m| m.create()).collect();
+            return Ok(plan);
+        };
+        // Zone 0 can be neither renamed nor given locations
+        if zone.id != 0 {
+            let mut update = None;
+            if zone.name != spec.name {
+                update = Some(ShippingZoneUpdate::default().name(&spec.name));
+            }
+            if let Some(order) = spec.order.filter(|order| *order != zone.order) {
+                update = Some(update.unwrap_or_default().order(order));
+            }
+            plan.update_zone = update;
+            let current = self
+                .list_all_subentities::<ShippingZoneLocation>(zone.id)
+                .await?
+                .into_iter()
+                .collect::<BTreeSet<_>>();
+            let desired = spec.locations.iter().cloned().collect::<BTreeSet<_>>();
+            if current != desired {
+                plan.locations = Some(spec.locations.clone());
+            }
+        }
+        let mut current = self
+            .list_all_subentities::<ShippingZoneMethod>(zone.id)
+            .await?
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+        for method in &spec.methods {
+            let existing = current
+                .iter_mut()
+                .find(|m| m.as_ref().is_some_and(|m| m.method_id == method.method_id))
+                .and_then(Option::take);
+            match existing {
+                Some(existing) => {
+                    let update = method.update(&existing);
+                    if !update.is_empty() {
+                        plan.update_methods.push((existing.instance_id, update));
+                    }
+                }
+                None => plan.include_methods.push(method.create()),
+            }
+        }
+        plan.delete_methods = current
+            .into_iter()
+            .flatten()
+            .map(|m| m.instance_id)
+            .collect();
+        Ok(plan)
+    }
+    /// This API applies a shipping zone plan and returns the resulting zone.
+    pub async fn apply_shipping_plan(&self, plan: &ShippingZonePlan) -> Result<ShippingZone> {
+        let mut zone = match (&plan.zone, &plan.create_zone) {
+            (Some(zone), _) => zone.clone(),
+            (None, Some(create)) => self.create::<ShippingZone>(create).await?,
+            (None, None) => {
+                return Err(Error::Config(String::from(
+                    "shipping zone plan has neither a zone nor a zone to create",
+                )))
+            }
+        };
+        if let Some(update) = &plan.update_zone {
+            zone = self.update::<ShippingZone>(zone.id, update).await?;
+        }
+        if let Some(locations) = &plan.locations {
+            self.replace_shipping_zone_locations(zone.id, locations.clone())
+                .await?;
+        }
+        for instance_id in &plan.delete_methods {
+            self.delete_subentity::<ShippingZoneMethod>(zone.id, *instance_id)
+                .await?;
+        }
+        for (instance_id, update) in &plan.update_methods {
+            self.update_subentity::<ShippingZoneMethod>(zone.id, *instance_id, update)
+                .await?;
+        }
+        for include in &plan.include_methods {
+            self.create_subentity::<ShippingZoneMethod>(zone.id, include)
+                .await?;
+        }
+        Ok(zone)
+    }
+    /// This API brings a shipping zone to its spec, doing nothing if it already matches.
+    pub async fn apply_shipping_zone(&self, spec: &ShippingZoneSpec) -> Result<ShippingZone> {
+        let plan = self.plan_shipping_zone(spec).await?;
+        self.apply_shipping_plan(&plan).await
+    }
+}
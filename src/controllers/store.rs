@@ -0,0 +1,537 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    ApiClient, Error, Event, PaymentGateway, Resource, Result, TaxClass, TaxRate, Topic, Webhook,
+    WebhookStatus,
+};
+
+use super::{
+    payment_gateways::PaymentGatewayUpdate,
+    settings::{same_value, SettingsDiff},
+    shipping_zones::{ShippingZonePlan, ShippingZoneSpec},
+};
+
+/// Desired store configuration, read from the `[store]` section of the [`crate::Config`] file.
+///
+/// Only declared resources are managed: tax classes, tax rates and webhooks are created or updated but never deleted,
+/// declared shipping zones are brought to their spec as a whole.
+///
+/// ```toml
+/// [store]
+/// tax_classes = ["Reduced rate"]
+///
+/// [[store.tax_rates]]
+/// country = "DE"
+/// rate = "19"
+/// name = "MwSt"
+///
+/// [[store.shipping_zones]]
+/// name = "Germany"
+/// locations = [{ code = "DE", type = "country" }]
+/// methods = [{ method_id = "flat_rate", settings = { cost = "5" } }]
+///
+/// [store.payment_gateways.bacs]
+/// enabled = true
+/// settings = { instructions = "Pay within 3 days" }
+///
+/// [store.settings.general]
+/// woocommerce_currency = "EUR"
+///
+/// [[store.webhooks]]
+/// name = "Orders"
+/// topic = "order.created"
+/// delivery_url = "https://example.com/hooks/orders"
+/// ```
+///
+/// It can also live in a file of its own, see [`StoreConfig::from_path`]:
+///
+/// ```yaml
+/// tax_classes: [Reduced rate]
+/// tax_rates:
+///   - { country: DE, rate: "19", name: MwSt }
+/// payment_gateways:
+///   bacs: { enabled: true }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StoreConfig {
+    /// Tax class names.
+    #[serde(default)]
+    pub tax_classes: Vec<String>,
+    /// Tax rates.
+    #[serde(default)]
+    pub tax_rates: Vec<TaxRateSpec>,
+    /// Shipping zones.
+    #[serde(default)]
+    pub shipping_zones: Vec<ShippingZoneSpec>,
+    /// Payment gateways keyed by gateway ID.
+    #[serde(default)]
+    pub payment_gateways: BTreeMap<String, PaymentGatewaySpec>,
+    /// Setting values keyed by group ID and option ID.
+    #[serde(default)]
+    pub settings: BTreeMap<String, BTreeMap<String, Value>>,
+    /// Webhooks, matched by name.
+    #[serde(default)]
+    pub webhooks: Vec<WebhookSpec>,
+}
+impl StoreConfig {
+    /// Returns true if nothing is declared
+    pub fn is_empty(&self) -> bool {
+        self == &StoreConfig::default()
+    }
+    /// Parse a store configuration written in TOML, without the `[store]` prefix
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        toml::from_str(toml).map_err(|e| Error::Config(e.to_string()))
+    }
+    /// Parse a store configuration written in YAML
+    pub fn from_yaml(yaml: &str) -> Result<Self> {
+        serde_yaml::from_str(yaml).map_err(|e| Error::Config(e.to_string()))
+    }
+    /// Read a store configuration file, parsed as YAML for `.yaml` and `.yml` extensions and as TOML for `.toml`
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        match extension.to_ascii_lowercase().as_str() {
+            "yaml" | "yml" => Self::from_yaml(&std::fs::read_to_string(path)?),
+            "toml" => Self::from_toml(&std::fs::read_to_string(path)?),
+            _ => Err(Error::Config(format!(
+                "{} is not a .toml, .yaml or .yml store configuration",
+                path.display()
+            ))),
+        }
+    }
+}
+/// Desired tax rate, matched by country, state, postcodes, cities, class and priority
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TaxRateSpec {
+    /// Country ISO 3166 code.
+    #[serde(default)]
+    pub country: String,
+    /// State code.
+    #[serde(default)]
+    pub state: String,
+    /// Postcodes/ZIPs.
+    #[serde(default)]
+    pub postcodes: Vec<String>,
+    /// City names.
+    #[serde(default)]
+    pub cities: Vec<String>,
+    /// Tax rate.
+    pub rate: String,
+    /// Tax rate name.
+    #[serde(default)]
+    pub name: String,
+    /// Tax priority. Default is 1.
+    #[serde(default = "default_priority")]
+    pub priority: i32,
+    /// Whether this is a compound rate. Default is false.
+    #[serde(default)]
+    pub compound: bool,
+    /// Whether this tax rate also gets applied to shipping. Default is true.
+    #[serde(default = "default_shipping")]
+    pub shipping: bool,
+    /// Tax class. Default is standard.
+    #[serde(default = "default_class")]
+    pub class: String,
+}
+fn default_priority() -> i32 {
+    1
+}
+fn default_shipping() -> bool {
+    true
+}
+fn default_class() -> String {
+    String::from("standard")
+}
+impl TaxRateSpec {
+    fn matches(&self, rate: &TaxRate) -> bool {
+        fn sorted(values: &[String]) -> Vec<String> {
+            let mut values = values.iter().map(|v| v.to_uppercase()).collect::<Vec<_>>();
+            values.sort();
+            values
+        }
+        rate.country.eq_ignore_ascii_case(&self.country)
+            && rate.state.eq_ignore_ascii_case(&self.state)
+            && rate.class == self.class
+            && rate.priority == self.priority
+            && sorted(&rate.postcodes) == sorted(&self.postcodes)
+            && sorted(&rate.cities) == sorted(&self.cities)
+    }
+    fn is_same(&self, rate: &TaxRate) -> bool {
+        let same_rate = match (rate.rate.parse::<f64>(), self.rate.parse::<f64>()) {
+            (Ok(current), Ok(desired)) => current == desired,
+            _ => rate.rate == self.rate,
+        };
+        same_rate
+            && rate.name == self.name
+            && rate.compound == self.compound
+            && rate.shipping == self.shipping
+    }
+}
+impl Display for TaxRateSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}%", self.name, self.rate)?;
+        let location = [self.country.as_str(), self.state.as_str()]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(":");
+        if !location.is_empty() {
+            write!(f, " in {location}")?;
+        }
+        write!(f, " ({})", self.class)
+    }
+}
+/// Desired state of a payment gateway, unset fields are left as is
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PaymentGatewaySpec {
+    /// Payment gateway enabled status.
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// Payment gateway title on checkout.
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Payment gateway description on checkout.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Payment gateway sort order.
+    #[serde(default)]
+    pub order: Option<i32>,
+    /// Setting values keyed by setting ID.
+    #[serde(default)]
+    pub settings: BTreeMap<String, Value>,
+}
+impl PaymentGatewaySpec {
+    /// Changes needed to turn the gateway into this spec, None if there are none
+    fn update(&self, gateway: &PaymentGateway) -> Option<PaymentGatewayUpdate> {
+        let mut update = PaymentGateway::update();
+        let mut changed = false;
+        if let Some(enabled) = self.enabled.filter(|e| *e != gateway.enabled) {
+            update = update.enabled(enabled);
+            changed = true;
+        }
+        if let Some(title) = self.title.as_ref().filter(|t| **t != gateway.title) {
+            update = update.title(title);
+            changed = true;
+        }
+        if let Some(description) = self
+            .description
+            .as_ref()
+            .filter(|d| **d != gateway.description)
+        {
+            update = update.description(description);
+            changed = true;
+        }
+        if let Some(order) = self.order.filter(|o| o.to_string() != gateway.order) {
+            update = update.order(order);
+            changed = true;
+        }
+        for (id, value) in &self.settings {
+            let same = gateway
+                .settings
+                .get(id)
+                .is_some_and(|current| same_value(&current.value, value));
+            if !same {
+                update = update.setting(id, value.clone());
+                changed = true;
+            }
+        }
+        changed.then(|| update.build())
+    }
+}
+/// Desired webhook, matched by name
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebhookSpec {
+    /// A friendly name for the webhook.
+    pub name: String,
+    /// Webhook topic, e.g. order.created.
+    pub topic: Topic,
+    /// The URL where the webhook payload is delivered.
+    pub delivery_url: String,
+    /// Secret key used to sign deliveries, left as is when not set.
+    #[serde(default)]
+    pub secret: Option<String>,
+    /// Webhook status, left as is when not set.
+    #[serde(default)]
+    pub status: Option<WebhookStatus>,
+}
+impl WebhookSpec {
+    fn resource_event(&self) -> Result<(Resource, Event)> {
+        self.topic
+            .parts()
+            .ok_or_else(|| Error::Config(format!("unsupported webhook topic {}", self.topic)))
+    }
+    fn is_same(&self, webhook: &Webhook) -> bool {
+        webhook.topic == self.topic
+            && webhook.delivery_url == self.delivery_url
+            && self.status.as_ref().map_or(true, |s| *s == webhook.status)
+            && self
+                .secret
+                .as_ref()
+                .map_or(true, |s| webhook.secret.as_ref() == Some(s))
+    }
+}
+/// Changes needed to bring the store to a [`StoreConfig`]
+#[derive(Debug, Clone, Default)]
+pub struct StorePlan {
+    /// Tax classes to create.
+    pub create_tax_classes: Vec<String>,
+    /// Tax rates to create.
+    pub create_tax_rates: Vec<TaxRateSpec>,
+    /// Tax rates to update, keyed by tax rate ID.
+    pub update_tax_rates: Vec<(i32, TaxRateSpec)>,
+    /// Shipping zones that differ from their spec.
+    pub shipping_zones: Vec<ShippingZonePlan>,
+    /// Payment gateway updates keyed by gateway ID.
+    pub payment_gateways: Vec<(String, PaymentGatewayUpdate)>,
+    /// Setting groups that differ from the desired values.
+    pub settings: Vec<SettingsDiff>,
+    /// Webhooks to create.
+    pub create_webhooks: Vec<WebhookSpec>,
+    /// Webhooks to update, keyed by webhook ID.
+    pub update_webhooks: Vec<(i32, WebhookSpec)>,
+}
+impl StorePlan {
+    /// Returns true if the store already matches its configuration
+    pub fn is_empty(&self) -> bool {
+        self.create_tax_classes.is_empty()
+            && self.create_tax_rates.is_empty()
+            && self.update_tax_rates.is_empty()
+            && self.shipping_zones.is_empty()
+            && self.payment_gateways.is_empty()
+            && self.settings.iter().all(SettingsDiff::is_empty)
+            && self.create_webhooks.is_empty()
+            && self.update_webhooks.is_empty()
+    }
+}
+impl Display for StorePlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            writeln!(f, "No changes")?;
+        }
+        for name in &self.create_tax_classes {
+            writeln!(f, "+ tax class {name}")?;
+        }
+        for rate in &self.create_tax_rates {
+            writeln!(f, "+ tax rate {rate}")?;
+        }
+        for (id, rate) in &self.update_tax_rates {
+            writeln!(f, "~ tax rate {id}: {rate}")?;
+        }
+        for zone in &self.shipping_zones {
+            let sign = if zone.zone.is_some() { "~" } else { "+" };
+            write!(f, "{sign} shipping zone {}", zone.name)?;
+            if zone.locations.is_some() {
+                write!(f, ", locations replaced")?;
+            }
+            for (count, action) in [
+                (zone.include_methods.len(), "added"),
+                (zone.update_methods.len(), "updated"),
+                (zone.delete_methods.len(), "deleted"),
+            ] {
+                if count > 0 {
+                    write!(f, ", {count} method(s) {action}")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        for (id, _) in &self.payment_gateways {
+            writeln!(f, "~ payment gateway {id}")?;
+        }
+        for diff in &self.settings {
+            for change in &diff.changes {
+                writeln!(
+                    f,
+                    "~ setting {}.{}: {} -> {}",
+                    diff.group_id, change.id, change.current, change.desired
+                )?;
+            }
+            for id in &diff.unknown {
+                writeln!(f, "! unknown setting {}.{id}", diff.group_id)?;
+            }
+        }
+        for webhook in &self.create_webhooks {
+            writeln!(f, "+ webhook {} ({})", webhook.name, webhook.topic)?;
+        }
+        for (id, webhook) in &self.update_webhooks {
+            writeln!(f, "~ webhook {id} {} ({})", webhook.name, webhook.topic)?;
+        }
+        Ok(())
+    }
+}
+impl ApiClient {
+    /// This API compares the live store with the desired configuration and returns the changes needed, without applying them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use rust_woocommerce::{ApiClient, Config};
+    /// use tracing::info;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     tracing_subscriber::fmt::init();
+    ///     let config = Config::new("woo.toml")?;
+    ///     let client = ApiClient::new(&config)?;
+    ///     let plan = client.plan_store(&config.store).await?;
+    ///     info!("Plan:\n{plan}");
+    ///     client.apply_store(&plan).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn plan_store(&self, config: &StoreConfig) -> Result<StorePlan> {
+        let mut plan = StorePlan::default();
+        if !config.tax_classes.is_empty() {
+            let current = self.list_all::<TaxClass>().await?;
+            plan.create_tax_classes = config
+                .tax_classes
+                .iter()
+                .filter(|name| !current.iter().any(|c| c.name == **name))
+                .cloned()
+                .collect();
+        }
+        if !config.tax_rates.is_empty() {
+            let mut current = self
+                .list_all::<TaxRate>()
+                .await?
+                .into_iter()
+                .map(Some)
+                .collect::<Vec<_>>();
+            for spec in &config.tax_rates {
+                let existing = current
+                    .iter_mut()
+                    .find(|r| r.as_ref().is_some_and(|r| spec.matches(r)))
+                    .and_then(Option::take);
+                match existing {
+                    Some(rate) if spec.is_same(&rate) => {}
+                    Some(rate) => plan.update_tax_rates.push((rate.id, spec.clone())),
+                    None => plan.create_tax_rates.push(spec.clone()),
+                }
+            }
+        }
+        for spec in &config.shipping_zones {
+            let zone = self.plan_shipping_zone(spec).await?;
+            if !zone.is_empty() {
+                plan.shipping_zones.push(zone);
+            }
+        }
+        for (id, spec) in &config.payment_gateways {
+            let gateway = self.retrieve::<PaymentGateway>(id.as_str()).await?;
+            if let Some(update) = spec.update(&gateway) {
+                plan.payment_gateways.push((id.clone(), update));
+            }
+        }
+        for (group_id, desired) in &config.settings {
+            let diff = self.diff_settings(group_id, desired).await?;
+            if !diff.changes.is_empty() || !diff.unknown.is_empty() {
+                plan.settings.push(diff);
+            }
+        }
+        if !config.webhooks.is_empty() {
+            let current = self.list_all::<Webhook>().await?;
+            for spec in &config.webhooks {
+                // Fail on unsupported topics while planning rather than half way through apply
+                spec.resource_event()?;
+                match current.iter().find(|w| w.name == spec.name) {
+                    Some(webhook) if spec.is_same(webhook) => {}
+                    Some(webhook) => plan.update_webhooks.push((webhook.id, spec.clone())),
+                    None => plan.create_webhooks.push(spec.clone()),
+                }
+            }
+        }
+        Ok(plan)
+    }
+    /// This API applies a store plan: tax classes, tax rates, shipping zones, payment gateways, settings and webhooks, in this order.
+    pub async fn apply_store(&self, plan: &StorePlan) -> Result<()> {
+        for name in &plan.create_tax_classes {
+            self.create::<TaxClass>(TaxClass::create().name(name).build())
+                .await?;
+        }
+        for spec in &plan.create_tax_rates {
+            let mut create = TaxRate::create();
+            create
+                .country(&spec.country)
+                .state(&spec.state)
+                .rate(&spec.rate)
+                .name(&spec.name)
+                .priority(spec.priority)
+                .class(&spec.class);
+            for postcode in &spec.postcodes {
+                create.postcode(postcode);
+            }
+            for city in &spec.cities {
+                create.city(city);
+            }
+            if spec.compound {
+                create.compound();
+            }
+            if !spec.shipping {
+                create.disable_shipping();
+            }
+            self.create::<TaxRate>(create.build()).await?;
+        }
+        for (id, spec) in &plan.update_tax_rates {
+            let mut update = TaxRate::update();
+            update.rate(&spec.rate).name(&spec.name);
+            if spec.compound {
+                update.compound();
+            } else {
+                update.not_compound();
+            }
+            if spec.shipping {
+                update.enable_shipping();
+            } else {
+                update.disable_shipping();
+            }
+            self.update::<TaxRate>(*id, update.build()).await?;
+        }
+        for zone in &plan.shipping_zones {
+            self.apply_shipping_plan(zone).await?;
+        }
+        for (id, update) in &plan.payment_gateways {
+            self.update::<PaymentGateway>(id.as_str(), update).await?;
+        }
+        for diff in &plan.settings {
+            self.apply_settings(diff).await?;
+        }
+        for spec in &plan.create_webhooks {
+            let (resource, event) = spec.resource_event()?;
+            let mut create = Webhook::create()
+                .name(&spec.name)
+                .resource(resource)
+                .event(event)
+                .delivery_url(&spec.delivery_url);
+            if let Some(secret) = &spec.secret {
+                create = create.secret(secret);
+            }
+            if let Some(status) = &spec.status {
+                create = create.status(status.clone());
+            }
+            self.create::<Webhook>(create.build()).await?;
+        }
+        for (id, spec) in &plan.update_webhooks {
+            let (resource, event) = spec.resource_event()?;
+            let mut update = Webhook::update()
+                .resource(resource)
+                .event(event)
+                .delivery_url(&spec.delivery_url);
+            if let Some(secret) = &spec.secret {
+                update = update.secret(secret);
+            }
+            if let Some(status) = &spec.status {
+                update = update.status(status.clone());
+            }
+            let update = update.build().map_err(|e| Error::Config(e.to_string()))?;
+            self.update::<Webhook>(*id, update).await?;
+        }
+        Ok(())
+    }
+}
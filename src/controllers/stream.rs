@@ -0,0 +1,237 @@
+use std::collections::VecDeque;
+
+use futures::Stream;
+use tokio::task::JoinHandle;
+use url::Url;
+
+use crate::{ApiClient, Error, Result};
+
+use super::{
+    decode,
+    query::{query_pairs, Query},
+    Entity,
+};
+
+/// Page size and prefetch depth used by [`ApiClient::stream_with_options`]
+#[derive(Debug, Clone, Copy)]
+pub struct StreamOptions {
+    per_page: i32,
+    prefetch: usize,
+}
+impl Default for StreamOptions {
+    fn default() -> Self {
+        Self {
+            per_page: 50,
+            prefetch: 1,
+        }
+    }
+}
+impl StreamOptions {
+    /// Number of items requested per page, from 1 to 100. Default is 50.
+    pub fn per_page(mut self, per_page: i32) -> Self {
+        self.per_page = per_page.clamp(1, 100);
+        self
+    }
+    /// Number of pages requested ahead of the consumer. Default is 1.
+    pub fn prefetch(mut self, prefetch: usize) -> Self {
+        self.prefetch = prefetch.max(1);
+        self
+    }
+}
+struct Page<T> {
+    items: Vec<T>,
+    total_pages: Option<i32>,
+    has_next: bool,
+}
+struct PageStream<T> {
+    client: ApiClient,
+    uri: Url,
+    query: Vec<(String, String)>,
+    options: StreamOptions,
+    next_page: i32,
+    total_pages: Option<i32>,
+    has_next: bool,
+    in_flight: VecDeque<JoinHandle<Result<Page<T>>>>,
+    buffer: VecDeque<T>,
+    done: bool,
+}
+impl<T> Drop for PageStream<T> {
+    fn drop(&mut self) {
+        for handle in &self.in_flight {
+            handle.abort();
+        }
+    }
+}
+impl<T: Entity> PageStream<T> {
+    /// Whether `next_page` may be requested with what is known about the listing so far
+    fn can_request(&self) -> bool {
+        match self.total_pages {
+            Some(total) => self.next_page <= total,
+            // Without X-WP-TotalPages follow the Link header one page at a time
+            None => self.next_page == 1 || (self.has_next && self.in_flight.is_empty()),
+        }
+    }
+    fn fill(&mut self) {
+        while self.in_flight.len() < self.options.prefetch && self.can_request() {
+            let client = self.client.clone();
+            let uri = self.uri.clone();
+            let query = self.query.clone();
+            let page = self.next_page;
+            let per_page = self.options.per_page;
+            self.in_flight.push_back(tokio::spawn(async move {
+                fetch_page(client, uri, query, page, per_page).await
+            }));
+            self.next_page += 1;
+        }
+    }
+    async fn next(&mut self) -> Option<Result<T>> {
+        loop {
+            if let Some(item) = self.buffer.pop_front() {
+                return Some(Ok(item));
+            }
+            if self.done {
+                return None;
+            }
+            self.fill();
+            let Some(handle) = self.in_flight.pop_front() else {
+                self.done = true;
+                return None;
+            };
+            let page = match handle.await {
+                Ok(Ok(page)) => page,
+                Ok(Err(e)) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(Error::Task(e.to_string())));
+                }
+            };
+            if page.total_pages.is_some() {
+                self.total_pages = page.total_pages;
+            }
+            self.has_next = page.has_next;
+            self.buffer.extend(page.items);
+        }
+    }
+}
+async fn fetch_page<T: Entity>(
+    client: ApiClient,
+    uri: Url,
+    query: Vec<(String, String)>,
+    page: i32,
+    per_page: i32,
+) -> Result<Page<T>> {
+    let response = client
+        .execute_with_retry(
+            client
+                .client
+                .get(uri)
+                .query(&query)
+                .query(&[("page", page), ("per_page", per_page)]),
+        )
+        .await?;
+    let total_pages = response
+        .headers()
+        .get("X-WP-TotalPages")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|p| p.parse::<i32>().ok());
+    let has_next = response
+        .headers()
+        .get_all(reqwest::header::LINK)
+        .iter()
+        .filter_map(|h| h.to_str().ok())
+        .flat_map(|h| h.split(','))
+        .any(|link| link.contains("rel=\"next\""));
+    let items = decode::<Vec<T>>(response, client.strict).await?;
+    Ok(Page {
+        items,
+        total_pages,
+        has_next,
+    })
+}
+impl ApiClient {
+    /// This API lazily streams entities of type T matching the query, requesting pages as they are consumed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use futures::StreamExt;
+    /// use rust_woocommerce::{ApiClient, Config, Order};
+    /// use tracing::info;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     tracing_subscriber::fmt::init();
+    ///     let config = Config::new("woo.toml")?;
+    ///     let client = ApiClient::new(&config)?;
+    ///     let mut orders = std::pin::pin!(client.stream::<Order>(Order::query()));
+    ///     while let Some(order) = orders.next().await {
+    ///         let order = order?;
+    ///         info!("Order {} total: {}", order.number, order.total);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn stream<T: Entity>(&self, query: impl Query<T>) -> impl Stream<Item = Result<T>> {
+        self.stream_with_options(query, StreamOptions::default())
+    }
+    /// This API lazily streams entities of type T matching the query with custom page size and prefetch depth.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use rust_woocommerce::{ApiClient, Config, Product, StreamOptions};
+    /// use tracing::info;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     tracing_subscriber::fmt::init();
+    ///     let config = Config::new("woo.toml")?;
+    ///     let client = ApiClient::new(&config)?;
+    ///     let options = StreamOptions::default().per_page(100).prefetch(3);
+    ///     let mut products =
+    ///         std::pin::pin!(client.stream_with_options(Product::query().sku("42"), options));
+    ///     if let Some(product) = products.try_next().await? {
+    ///         info!("Found product {}", product.name);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn stream_with_options<T: Entity>(
+        &self,
+        query: impl Query<T>,
+        options: StreamOptions,
+    ) -> impl Stream<Item = Result<T>> {
+        let state = self
+            .base_url
+            .join(&T::endpoint())
+            .map_err(Error::from)
+            .and_then(|uri| Ok((uri, query_pairs(&query)?)))
+            .map(|(uri, query)| PageStream {
+                client: self.clone(),
+                uri,
+                query,
+                options,
+                next_page: 1,
+                total_pages: None,
+                has_next: false,
+                in_flight: VecDeque::new(),
+                buffer: VecDeque::new(),
+                done: false,
+            });
+        futures::stream::unfold(Some(state), |state| async move {
+            match state? {
+                Ok(mut stream) => {
+                    let item = stream.next().await?;
+                    Some((item, Some(Ok(stream))))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        })
+    }
+}
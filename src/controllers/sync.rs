@@ -0,0 +1,436 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+use futures::{future::BoxFuture, TryStreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ApiClient, Customer, CustomerQuery, Error, Order, OrderQuery, OrderSortBy, Product,
+    ProductQuery, ProductSortBy, Result, SortOrder, StreamOptions,
+};
+
+use super::{query::Query, Entity};
+
+/// Entity that [`SyncEngine`] can mirror incrementally
+pub trait Syncable: Entity<Id = i32> {
+    /// Name the checkpoint of the entity is stored under.
+    const KEY: &'static str;
+    /// Query listing the entity.
+    type Query: Query<Self>;
+    /// Unique identifier of the entity
+    fn sync_id(&self) -> i32;
+    /// Last modification date of the entity from its `_gmt` field, or its creation date if it was never modified
+    fn modified_utc(&self) -> DateTime<Utc>;
+    /// Query listing entities modified after a GMT date, or all entities if there is none
+    fn changes_query(modified_after: Option<NaiveDateTime>) -> Self::Query;
+}
+impl Syncable for Product {
+    const KEY: &'static str = "products";
+    type Query = ProductQuery;
+
+    fn sync_id(&self) -> i32 {
+        self.id
+    }
+    fn modified_utc(&self) -> DateTime<Utc> {
+        self.date_modified_utc()
+            .or_else(|| self.date_created_utc())
+            .unwrap_or_default()
+    }
+    fn changes_query(modified_after: Option<NaiveDateTime>) -> ProductQuery {
+        let query = Product::query()
+            .orderby(ProductSortBy::Modified)
+            .order(SortOrder::Asc);
+        match modified_after {
+            Some(date) => query.modified_after(date).dates_are_gmt(),
+            None => query,
+        }
+    }
+}
+impl Syncable for Order {
+    const KEY: &'static str = "orders";
+    type Query = OrderQuery;
+
+    fn sync_id(&self) -> i32 {
+        self.id
+    }
+    fn modified_utc(&self) -> DateTime<Utc> {
+        self.date_modified_utc()
+            .or_else(|| self.date_created_utc())
+            .unwrap_or_default()
+    }
+    fn changes_query(modified_after: Option<NaiveDateTime>) -> OrderQuery {
+        let query = Order::query()
+            .orderby(OrderSortBy::Modified)
+            .order(SortOrder::Asc);
+        match modified_after {
+            Some(date) => query.modified_after(date).dates_are_gmt(),
+            None => query,
+        }
+    }
+}
+/// The customers endpoint has no `modified_after` filter, every sync lists all customers and keeps the changed ones.
+impl Syncable for Customer {
+    const KEY: &'static str = "customers";
+    type Query = CustomerQuery;
+
+    fn sync_id(&self) -> i32 {
+        self.id
+    }
+    fn modified_utc(&self) -> DateTime<Utc> {
+        self.date_modified_utc()
+            .or_else(|| self.date_created_utc())
+            .unwrap_or_default()
+    }
+    fn changes_query(_modified_after: Option<NaiveDateTime>) -> CustomerQuery {
+        Customer::query()
+    }
+}
+/// Position of a sync, entities are ordered by modification date then ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Cursor {
+    /// Modification date of the last synced entity.
+    pub modified_gmt: DateTime<Utc>,
+    /// ID of the last synced entity.
+    pub id: i32,
+}
+impl Cursor {
+    fn of<T: Syncable>(entity: &T) -> Self {
+        Cursor {
+            modified_gmt: entity.modified_utc(),
+            id: entity.sync_id(),
+        }
+    }
+}
+/// Sync state of one entity type
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// Last synced change, `None` before the first sync.
+    pub cursor: Option<Cursor>,
+    /// IDs known to exist, used to detect deletions.
+    pub ids: BTreeSet<i32>,
+    /// Modification dates of the entities synced since the cursor, so that re-scanning the overlap does not report them again.
+    #[serde(default)]
+    pub recent: BTreeMap<i32, DateTime<Utc>>,
+    /// When the IDs were last reconciled with the store.
+    pub reconciled_at: Option<DateTime<Utc>>,
+}
+/// Change of a mirrored entity
+#[derive(Debug, Clone)]
+pub enum Change<T> {
+    /// Entity not seen before.
+    Created(T),
+    /// Entity modified since the last sync.
+    Updated(T),
+    /// Entity no longer listed by the store, deleted or trashed.
+    Deleted(i32),
+}
+impl<T: Syncable> Change<T> {
+    /// ID of the changed entity
+    pub fn id(&self) -> i32 {
+        match self {
+            Change::Created(entity) | Change::Updated(entity) => entity.sync_id(),
+            Change::Deleted(id) => *id,
+        }
+    }
+}
+/// Changes returned by [`SyncEngine::sync`], the checkpoint moves forward once they are passed to [`SyncEngine::commit`]
+#[derive(Debug, Clone)]
+pub struct SyncBatch<T> {
+    /// Created and updated entities in modification order, followed by deletions.
+    pub changes: Vec<Change<T>>,
+    checkpoint: Checkpoint,
+}
+impl<T> SyncBatch<T> {
+    /// Returns true if nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+    /// Checkpoint saved on commit
+    pub fn checkpoint(&self) -> &Checkpoint {
+        &self.checkpoint
+    }
+}
+/// Persists [`Checkpoint`]s between syncs.
+///
+/// Implement it over the database the entities are mirrored into to save the checkpoint in the same transaction.
+pub trait CheckpointStore: Send + Sync {
+    /// Checkpoint saved under a key, `None` if there is none yet
+    fn load<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<Checkpoint>>>;
+    /// Save the checkpoint of a key
+    fn save<'a>(&'a self, key: &'a str, checkpoint: &'a Checkpoint) -> BoxFuture<'a, Result<()>>;
+}
+/// In-process [`CheckpointStore`], every sync starts over after a restart
+#[derive(Debug, Default)]
+pub struct MemoryCheckpointStore {
+    checkpoints: Mutex<HashMap<String, Checkpoint>>,
+}
+impl MemoryCheckpointStore {
+    /// Empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl CheckpointStore for MemoryCheckpointStore {
+    fn load<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<Checkpoint>>> {
+        let checkpoints = self.checkpoints.lock().unwrap_or_else(|e| e.into_inner());
+        Box::pin(futures::future::ready(Ok(checkpoints.get(key).cloned())))
+    }
+    fn save<'a>(&'a self, key: &'a str, checkpoint: &'a Checkpoint) -> BoxFuture<'a, Result<()>> {
+        let mut checkpoints = self.checkpoints.lock().unwrap_or_else(|e| e.into_inner());
+        checkpoints.insert(key.to_owned(), checkpoint.clone());
+        Box::pin(futures::future::ready(Ok(())))
+    }
+}
+/// [`CheckpointStore`] keeping the checkpoints of all keys in one JSON file, replaced atomically on save
+#[derive(Debug)]
+pub struct FileCheckpointStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+impl FileCheckpointStore {
+    /// Store backed by the given file, created on the first save
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        FileCheckpointStore {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+    fn read(&self) -> Result<HashMap<String, Checkpoint>> {
+        let payload = match std::fs::read_to_string(&self.path) {
+            Ok(payload) => payload,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+            Err(e) => return Err(e.into()),
+        };
+        serde_json::from_str(&payload).map_err(|source| Error::Deserialize { source, payload })
+    }
+    fn write(&self, checkpoints: &HashMap<String, Checkpoint>) -> Result<()> {
+        let payload = serde_json::to_vec_pretty(checkpoints).map_err(std::io::Error::from)?;
+        let tmp = self.path.with_extension("tmp");
+        std::fs::write(&tmp, payload)?;
+        std::fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
+impl CheckpointStore for FileCheckpointStore {
+    fn load<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<Checkpoint>>> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let loaded = self.read().map(|mut checkpoints| checkpoints.remove(key));
+        Box::pin(futures::future::ready(loaded))
+    }
+    fn save<'a>(&'a self, key: &'a str, checkpoint: &'a Checkpoint) -> BoxFuture<'a, Result<()>> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let saved = self.read().and_then(|mut checkpoints| {
+            checkpoints.insert(key.to_owned(), checkpoint.clone());
+            self.write(&checkpoints)
+        });
+        Box::pin(futures::future::ready(saved))
+    }
+}
+/// Only the `id` of an entity, to reconcile IDs without downloading whole entities
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
+struct SyncId<T> {
+    id: i32,
+    #[serde(skip)]
+    entity: PhantomData<fn() -> T>,
+}
+impl<T: Entity> Entity for SyncId<T> {
+    type Id = i32;
+    fn endpoint() -> String {
+        T::endpoint()
+    }
+    fn child_endpoint(parent_id: i32) -> String {
+        T::child_endpoint(parent_id)
+    }
+}
+#[derive(Serialize)]
+#[serde(bound = "")]
+struct IdsQuery<T> {
+    #[serde(rename = "_fields")]
+    fields: &'static str,
+    #[serde(skip)]
+    entity: PhantomData<fn() -> T>,
+}
+impl<T: Entity> Query<SyncId<T>> for IdsQuery<T> {}
+/// Mirrors entities incrementally with `modified_after` cursors, detecting deletions by reconciling IDs.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use anyhow::Result;
+/// use rust_woocommerce::{ApiClient, Change, Config, FileCheckpointStore, Product, SyncEngine};
+/// use tracing::info;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     tracing_subscriber::fmt::init();
+///     let config = Config::new("woo.toml")?;
+///     let client = ApiClient::new(&config)?;
+///     let engine = SyncEngine::new(client, Arc::new(FileCheckpointStore::new("sync.json")));
+///     let batch = engine.sync::<Product>().await?;
+///     for change in &batch.changes {
+///         match change {
+///             Change::Created(product) | Change::Updated(product) => {
+///                 info!("Upsert product {}", product.name)
+///             }
+///             Change::Deleted(id) => info!("Delete product {id}"),
+///         }
+///     }
+///     engine.commit(&batch).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct SyncEngine {
+    client: ApiClient,
+    store: Arc<dyn CheckpointStore>,
+    reconcile_every: Duration,
+    overlap: Duration,
+    options: StreamOptions,
+}
+impl std::fmt::Debug for SyncEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncEngine")
+            .field("reconcile_every", &self.reconcile_every)
+            .field("overlap", &self.overlap)
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+impl SyncEngine {
+    /// Engine syncing through the client and saving checkpoints in the store
+    pub fn new(client: ApiClient, store: Arc<dyn CheckpointStore>) -> Self {
+        SyncEngine {
+            client,
+            store,
+            reconcile_every: Duration::from_secs(60 * 60),
+            overlap: Duration::from_secs(5 * 60),
+            options: StreamOptions::default().per_page(100),
+        }
+    }
+    /// How often the known IDs are compared with the store to detect deletions. Default is one hour.
+    pub fn reconcile_every(mut self, interval: Duration) -> Self {
+        self.reconcile_every = interval;
+        self
+    }
+    /// How long before the start of a sync the next one starts listing again. Default is five minutes.
+    ///
+    /// Listings are paginated by offset, an entity modified during a sync moves to the end and the one after it
+    /// shifts onto the page already fetched, so it is only found by listing again. The overlap also absorbs clock
+    /// skew with the store. Entities already synced with the same modification date are not reported twice.
+    pub fn overlap(mut self, overlap: Duration) -> Self {
+        self.overlap = overlap;
+        self
+    }
+    /// Page size and prefetch depth of the listings. Default is 100 items per page.
+    pub fn stream_options(mut self, options: StreamOptions) -> Self {
+        self.options = options;
+        self
+    }
+    /// Saved checkpoint of an entity type
+    pub async fn checkpoint<T: Syncable>(&self) -> Result<Checkpoint> {
+        Ok(self.store.load(T::KEY).await?.unwrap_or_default())
+    }
+    /// Fetch the changes since the saved checkpoint, reconciling IDs when due.
+    ///
+    /// The first sync lists every entity as created.
+    pub async fn sync<T: Syncable>(&self) -> Result<SyncBatch<T>> {
+        let checkpoint = self.checkpoint::<T>().await?;
+        let due = checkpoint.reconciled_at.map_or(true, |at| {
+            (Utc::now() - at)
+                .to_std()
+                .is_ok_and(|elapsed| elapsed >= self.reconcile_every)
+        });
+        self.fetch(checkpoint, due).await
+    }
+    /// Fetch the changes since the saved checkpoint and reconcile IDs regardless of when it was last done
+    pub async fn reconcile<T: Syncable>(&self) -> Result<SyncBatch<T>> {
+        let checkpoint = self.checkpoint::<T>().await?;
+        self.fetch(checkpoint, true).await
+    }
+    /// Save the checkpoint of a batch once its changes are applied
+    pub async fn commit<T: Syncable>(&self, batch: &SyncBatch<T>) -> Result<()> {
+        self.store.save(T::KEY, &batch.checkpoint).await
+    }
+    async fn fetch<T: Syncable>(
+        &self,
+        mut checkpoint: Checkpoint,
+        reconcile: bool,
+    ) -> Result<SyncBatch<T>> {
+        let started_at = Utc::now();
+        let cursor = checkpoint.cursor;
+        // modified_after is exclusive and has a one second resolution, the cursor filters what was already seen
+        let modified_after = cursor.map(|c| c.modified_gmt.naive_utc() - TimeDelta::seconds(1));
+        let recent = &checkpoint.recent;
+        let mut entities: Vec<T> = self
+            .client
+            .stream_with_options(T::changes_query(modified_after), self.options)
+            .try_filter(|entity| {
+                let unseen = recent.get(&entity.sync_id()) != Some(&entity.modified_utc());
+                futures::future::ready(unseen && Some(Cursor::of(entity)) > cursor)
+            })
+            .try_collect()
+            .await?;
+        entities.sort_by_key(Cursor::of);
+        // Entities skipped by the pagination were modified before the sync started, the next one lists them again
+        let horizon = TimeDelta::from_std(self.overlap)
+            .ok()
+            .and_then(|overlap| started_at.checked_sub_signed(overlap))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        if let Some(last) = entities.last().map(Cursor::of).or(cursor) {
+            checkpoint.cursor = Some(last.min(Cursor {
+                modified_gmt: horizon,
+                id: 0,
+            }));
+        }
+        let mut changes = Vec::with_capacity(entities.len());
+        for entity in entities {
+            checkpoint
+                .recent
+                .insert(entity.sync_id(), entity.modified_utc());
+            if checkpoint.ids.insert(entity.sync_id()) {
+                changes.push(Change::Created(entity));
+            } else {
+                changes.push(Change::Updated(entity));
+            }
+        }
+        if cursor.is_none() {
+            // The first sync listed every entity
+            checkpoint.reconciled_at = Some(started_at);
+        } else if reconcile {
+            let live: BTreeSet<i32> = self
+                .client
+                .stream_with_options(
+                    IdsQuery::<T> {
+                        fields: "id",
+                        entity: PhantomData,
+                    },
+                    self.options,
+                )
+                .map_ok(|entity: SyncId<T>| entity.id)
+                .try_collect()
+                .await?;
+            let deleted: Vec<i32> = checkpoint.ids.difference(&live).copied().collect();
+            for id in deleted {
+                checkpoint.ids.remove(&id);
+                checkpoint.recent.remove(&id);
+                changes.push(Change::Deleted(id));
+            }
+            checkpoint.reconciled_at = Some(started_at);
+        }
+        if let Some(cursor) = checkpoint.cursor {
+            checkpoint
+                .recent
+                .retain(|_, modified| *modified >= cursor.modified_gmt);
+        }
+        Ok(SyncBatch {
+            changes,
+            checkpoint,
+        })
+    }
+}
@@ -178,11 +178,21 @@ impl TaxRateUpdateBuilder {
         let _ = self.compound.insert(true);
         self
     }
+    /// Makes this a compound rate back into a regular one.
+    pub fn not_compound(&mut self) -> &mut Self {
+        let _ = self.compound.insert(false);
+        self
+    }
     /// Whether or not this tax rate also gets applied to shipping. Default is true.
     pub fn disable_shipping(&mut self) -> &mut Self {
         let _ = self.shipping.insert(false);
         self
     }
+    /// Applies this tax rate to shipping again.
+    pub fn enable_shipping(&mut self) -> &mut Self {
+        let _ = self.shipping.insert(true);
+        self
+    }
     /// Indicates the order that will appear in queries.
     pub fn order(&mut self, order: i32) -> &mut Self {
         let _ = self.order.insert(order);
@@ -0,0 +1,59 @@
+use futures::future::BoxFuture;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, SET_COOKIE};
+use reqwest::StatusCode;
+
+use crate::{ApiClient, Result};
+
+/// Layer that sends the requests of an [`ApiClient`], after authentication, retries and caching were applied.
+///
+/// [`reqwest::Client`] is the default. Other implementations record or replay traffic, see
+/// [`crate::RecordingTransport`] and [`crate::ReplayTransport`], or route requests through custom middleware.
+pub trait Transport: std::fmt::Debug + Send + Sync {
+    /// Send a request and return the response, whatever its status
+    fn execute(&self, request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>>;
+}
+impl Transport for reqwest::Client {
+    fn execute(&self, request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>> {
+        Box::pin(async move { Ok(reqwest::Client::execute(self, request).await?) })
+    }
+}
+/// Shared transport, e.g. to inspect a [`crate::ReplayTransport`] after the client used it
+impl<T: Transport + ?Sized> Transport for std::sync::Arc<T> {
+    fn execute(&self, request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>> {
+        (**self).execute(request)
+    }
+}
+impl ApiClient {
+    /// Send requests through a custom transport instead of the reqwest client.
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = std::sync::Arc::new(transport);
+        self
+    }
+}
+/// Response headers worth keeping, cookies are dropped
+pub(crate) fn header_pairs(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter(|(name, _)| *name != SET_COOKIE)
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_owned())))
+        .collect()
+}
+/// Build a response from stored parts, skipping headers that are not valid anymore
+pub(crate) fn build_response(
+    status: u16,
+    headers: &[(String, String)],
+    body: String,
+) -> reqwest::Response {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = StatusCode::from_u16(status).unwrap_or(StatusCode::OK);
+    let header_map: &mut HeaderMap = response.headers_mut();
+    for (name, value) in headers {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::try_from(name.as_str()),
+            HeaderValue::try_from(value.as_str()),
+        ) {
+            header_map.append(name, value);
+        }
+    }
+    reqwest::Response::from(response)
+}
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use axum::body::Bytes;
+use axum::extract::{FromRef, FromRequest, Request};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+use crate::{DeliveryStore, Error, WebhookDelivery, WebhookError, WebhookHeaders, WebhookVerifier};
+
+/// Axum state checking webhook deliveries, optionally skipping deliveries that were already handled.
+///
+/// With a store, handlers call [`WebhookReceiver::commit`] once the delivery is handled. A delivery whose handler
+/// failed is not recorded and reaches the handler again when WooCommerce retries it.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use axum::{extract::State, http::StatusCode, routing::post, Router};
+/// use rust_woocommerce::{MemoryDeliveryStore, VerifiedWebhook, WebhookEvent, WebhookReceiver};
+///
+/// async fn handle(
+///     State(receiver): State<WebhookReceiver>,
+///     VerifiedWebhook(delivery): VerifiedWebhook,
+/// ) -> StatusCode {
+///     if let WebhookEvent::Order { event, order } = &delivery.event {
+///         tracing::info!("Order {} {event}", order.id);
+///     }
+///     match receiver.commit(&delivery).await {
+///         Ok(()) => StatusCode::OK,
+///         Err(_) => StatusCode::INTERNAL_SERVER_ERROR,
+///     }
+/// }
+///
+/// let receiver = WebhookReceiver::new("secret").with_store(Arc::new(MemoryDeliveryStore::default()));
+/// let app: Router = Router::new().route("/hooks", post(handle)).with_state(receiver);
+/// ```
+#[derive(Clone)]
+pub struct WebhookReceiver {
+    verifier: WebhookVerifier,
+    store: Option<Arc<dyn DeliveryStore>>,
+}
+impl std::fmt::Debug for WebhookReceiver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookReceiver")
+            .field("verifier", &self.verifier)
+            .field("dedup", &self.store.is_some())
+            .finish()
+    }
+}
+impl WebhookReceiver {
+    /// Receiver for deliveries signed with the given webhook secret
+    pub fn new(secret: impl Into<String>) -> Self {
+        WebhookReceiver {
+            verifier: WebhookVerifier::new(secret),
+            store: None,
+        }
+    }
+    /// Skip deliveries whose `X-WC-Webhook-Delivery-ID` was recorded in the store by [`WebhookReceiver::commit`]
+    pub fn with_store(mut self, store: Arc<dyn DeliveryStore>) -> Self {
+        let _ = self.store.insert(store);
+        self
+    }
+    /// Verify a delivery and reject it if its ID was already recorded
+    pub async fn receive(
+        &self,
+        headers: WebhookHeaders,
+        body: &[u8],
+    ) -> Result<WebhookDelivery, WebhookRejection> {
+        let delivery = self.verifier.verify(headers, body)?;
+        if let (Some(store), Some(delivery_id)) = (&self.store, &delivery.headers.delivery_id) {
+            if store.contains(delivery_id).await? {
+                return Err(WebhookRejection::Duplicate(delivery_id.clone()));
+            }
+        }
+        Ok(delivery)
+    }
+    /// Record the ID of a delivery once it was handled, so that retries of it are skipped
+    pub async fn commit(&self, delivery: &WebhookDelivery) -> crate::Result<()> {
+        match (&self.store, &delivery.headers.delivery_id) {
+            (Some(store), Some(delivery_id)) => store.record(delivery_id).await,
+            _ => Ok(()),
+        }
+    }
+}
+/// Extractor handing handlers a verified [`WebhookDelivery`], the state must provide a [`WebhookReceiver`]
+#[derive(Debug, Clone)]
+pub struct VerifiedWebhook(pub WebhookDelivery);
+#[async_trait]
+impl<S> FromRequest<S> for VerifiedWebhook
+where
+    S: Send + Sync,
+    WebhookReceiver: FromRef<S>,
+{
+    type Rejection = WebhookRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let receiver = WebhookReceiver::from_ref(state);
+        let headers = WebhookHeaders::from_header_map(req.headers());
+        let body = Bytes::from_request(req, state)
+            .await
+            .map_err(|e| WebhookRejection::Body(e.body_text()))?;
+        receiver.receive(headers, &body).await.map(VerifiedWebhook)
+    }
+}
+/// Reasons [`VerifiedWebhook`] does not reach the handler
+#[derive(Debug)]
+pub enum WebhookRejection {
+    /// Unsigned, forged or undecodable delivery.
+    Invalid(WebhookError),
+    /// Delivery ID already handled, answered with 200 so that WooCommerce stops retrying.
+    Duplicate(String),
+    /// Request body could not be read.
+    Body(String),
+    /// Delivery store failed.
+    Store(Error),
+}
+impl From<WebhookError> for WebhookRejection {
+    fn from(value: WebhookError) -> Self {
+        WebhookRejection::Invalid(value)
+    }
+}
+impl From<Error> for WebhookRejection {
+    fn from(value: Error) -> Self {
+        WebhookRejection::Store(value)
+    }
+}
+impl IntoResponse for WebhookRejection {
+    fn into_response(self) -> Response {
+        match self {
+            WebhookRejection::Invalid(e @ WebhookError::Payload(_)) => {
+                (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+            }
+            WebhookRejection::Invalid(e) => {
+                (StatusCode::UNAUTHORIZED, e.to_string()).into_response()
+            }
+            WebhookRejection::Duplicate(_) => StatusCode::OK.into_response(),
+            WebhookRejection::Body(e) => (StatusCode::BAD_REQUEST, e).into_response(),
+            WebhookRejection::Store(e) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+            }
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::Mutex;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use futures::future::BoxFuture;
+use hmac::{Hmac, Mac};
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{Coupon, Customer, Event, Order, Product, Resource, Result, Topic, WebhookError};
+
+/// `X-WC-Webhook-Source` header name.
+pub const SOURCE_HEADER: &str = "x-wc-webhook-source";
+/// `X-WC-Webhook-Topic` header name.
+pub const TOPIC_HEADER: &str = "x-wc-webhook-topic";
+/// `X-WC-Webhook-Resource` header name.
+pub const RESOURCE_HEADER: &str = "x-wc-webhook-resource";
+/// `X-WC-Webhook-Event` header name.
+pub const EVENT_HEADER: &str = "x-wc-webhook-event";
+/// `X-WC-Webhook-Signature` header name.
+pub const SIGNATURE_HEADER: &str = "x-wc-webhook-signature";
+/// `X-WC-Webhook-ID` header name.
+pub const ID_HEADER: &str = "x-wc-webhook-id";
+/// `X-WC-Webhook-Delivery-ID` header name.
+pub const DELIVERY_ID_HEADER: &str = "x-wc-webhook-delivery-id";
+
+/// `X-WC-Webhook-*` headers of a delivery
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WebhookHeaders {
+    /// URL of the store that sent the delivery.
+    pub source: Option<String>,
+    /// Webhook topic, e.g. order.created.
+    pub topic: Option<Topic>,
+    /// Webhook resource.
+    pub resource: Option<Resource>,
+    /// Webhook event, the action name for action topics.
+    pub event: Option<Event>,
+    /// Base64 encoded HMAC-SHA256 of the body.
+    pub signature: Option<String>,
+    /// ID of the webhook that sent the delivery.
+    pub webhook_id: Option<i32>,
+    /// Unique ID of the delivery, repeated when WooCommerce retries it.
+    pub delivery_id: Option<String>,
+}
+impl WebhookHeaders {
+    /// Read the headers from `(name, value)` pairs, names are case insensitive
+    pub fn from_pairs<'a>(pairs: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut headers = WebhookHeaders::default();
+        for (name, value) in pairs {
+            let value = value.trim();
+            match name.to_ascii_lowercase().as_str() {
+                SOURCE_HEADER => headers.source = Some(value.to_owned()),
+                TOPIC_HEADER => headers.topic = Some(Topic::from(value)),
+                RESOURCE_HEADER => headers.resource = Some(Resource::from(value.to_owned())),
+                EVENT_HEADER => headers.event = Some(Event::from(value.to_owned())),
+                SIGNATURE_HEADER => headers.signature = Some(value.to_owned()),
+                ID_HEADER => headers.webhook_id = value.parse().ok(),
+                DELIVERY_ID_HEADER => headers.delivery_id = Some(value.to_owned()),
+                _ => {}
+            }
+        }
+        headers
+    }
+    /// Read the headers from an HTTP header map
+    pub fn from_header_map(headers: &HeaderMap) -> Self {
+        Self::from_pairs(
+            headers
+                .iter()
+                .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?))),
+        )
+    }
+    /// Topic of the delivery, from its own header or from the resource and event headers
+    pub fn topic(&self) -> Option<Topic> {
+        match (&self.topic, &self.resource, &self.event) {
+            (Some(topic), _, _) => Some(topic.clone()),
+            (None, Some(resource), Some(event)) => {
+                Some(Topic::new(resource.clone(), event.clone()))
+            }
+            _ => None,
+        }
+    }
+}
+/// Typed payload of a webhook delivery
+#[derive(Debug, Clone)]
+pub enum WebhookEvent {
+    /// Request sent by WooCommerce when a webhook is saved, its body is `webhook_id=<id>` and it is not signed.
+    Ping {
+        /// ID of the webhook.
+        webhook_id: i32,
+    },
+    /// Coupon created, updated or restored.
+    Coupon {
+        /// Webhook event.
+        event: Event,
+        /// Coupon after the change.
+        coupon: Box<Coupon>,
+    },
+    /// Customer created, updated or restored.
+    Customer {
+        /// Webhook event.
+        event: Event,
+        /// Customer after the change.
+        customer: Box<Customer>,
+    },
+    /// Order created, updated or restored.
+    Order {
+        /// Webhook event.
+        event: Event,
+        /// Order after the change.
+        order: Box<Order>,
+    },
+    /// Product created, updated or restored.
+    Product {
+        /// Webhook event.
+        event: Event,
+        /// Product after the change.
+        product: Box<Product>,
+    },
+    /// Resource deleted, WooCommerce only sends its ID.
+    Deleted {
+        /// Webhook resource.
+        resource: Resource,
+        /// ID of the deleted resource.
+        id: i32,
+    },
+    /// Action or plugin defined topic, the payload is left as JSON.
+    Other {
+        /// Webhook topic.
+        topic: Topic,
+        /// Raw payload.
+        payload: Value,
+    },
+}
+#[derive(Deserialize)]
+struct DeletedPayload {
+    id: i32,
+}
+impl WebhookEvent {
+    /// Returns true for the ping sent when a webhook is saved
+    pub fn is_ping(&self) -> bool {
+        matches!(self, WebhookEvent::Ping { .. })
+    }
+    /// Deserialize a body according to the topic in the headers, without checking its signature
+    pub fn parse(headers: &WebhookHeaders, body: &[u8]) -> std::result::Result<Self, WebhookError> {
+        if let Some(webhook_id) = ping_webhook_id(body) {
+            return Ok(WebhookEvent::Ping { webhook_id });
+        }
+        let topic = headers
+            .topic()
+            .ok_or(WebhookError::MissingHeader(TOPIC_HEADER))?;
+        let parsed =
+            match topic {
+                Topic::Resource(
+                    resource @ (Resource::Coupon
+                    | Resource::Customer
+                    | Resource::Order
+                    | Resource::Product),
+                    Event::Deleted,
+                ) => serde_json::from_slice::<DeletedPayload>(body).map(|payload| {
+                    WebhookEvent::Deleted {
+                        resource,
+                        id: payload.id,
+                    }
+                }),
+                Topic::Resource(Resource::Coupon, event) => serde_json::from_slice(body)
+                    .map(|coupon| WebhookEvent::Coupon { event, coupon }),
+                Topic::Resource(Resource::Customer, event) => serde_json::from_slice(body)
+                    .map(|customer| WebhookEvent::Customer { event, customer }),
+                Topic::Resource(Resource::Order, event) => {
+                    serde_json::from_slice(body).map(|order| WebhookEvent::Order { event, order })
+                }
+                Topic::Resource(Resource::Product, event) => serde_json::from_slice(body)
+                    .map(|product| WebhookEvent::Product { event, product }),
+                topic => serde_json::from_slice(body)
+                    .map(|payload| WebhookEvent::Other { topic, payload }),
+            };
+        parsed.map_err(WebhookError::Payload)
+    }
+}
+/// ID from a `webhook_id=<id>` form body
+fn ping_webhook_id(body: &[u8]) -> Option<i32> {
+    let body = std::str::from_utf8(body).ok()?;
+    body.trim().strip_prefix("webhook_id=")?.parse().ok()
+}
+/// Verified and decoded webhook delivery
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    /// Delivery headers.
+    pub headers: WebhookHeaders,
+    /// Typed payload.
+    pub event: WebhookEvent,
+}
+/// Checks `X-WC-Webhook-Signature` against the webhook secret and decodes deliveries.
+///
+/// # Example
+///
+/// ```no_run
+/// use rust_woocommerce::{WebhookEvent, WebhookHeaders, WebhookVerifier};
+///
+/// fn handle(headers: &[(&str, &str)], body: &[u8]) -> rust_woocommerce::Result<()> {
+///     let verifier = WebhookVerifier::new("secret");
+///     let delivery = verifier.verify(WebhookHeaders::from_pairs(headers.iter().copied()), body)?;
+///     if let WebhookEvent::Order { event, order } = delivery.event {
+///         tracing::info!("Order {} {event}", order.id);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    secret: String,
+}
+impl std::fmt::Debug for WebhookVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookVerifier").finish_non_exhaustive()
+    }
+}
+impl WebhookVerifier {
+    /// Verifier for deliveries signed with the given webhook secret
+    pub fn new(secret: impl Into<String>) -> Self {
+        WebhookVerifier {
+            secret: secret.into(),
+        }
+    }
+    /// Base64 encoded HMAC-SHA256 of a body, as sent in `X-WC-Webhook-Signature`
+    pub fn sign(&self, body: &[u8]) -> String {
+        STANDARD.encode(self.mac(body).finalize().into_bytes())
+    }
+    /// Check a signature against a body in constant time
+    pub fn check_signature(
+        &self,
+        body: &[u8],
+        signature: &str,
+    ) -> std::result::Result<(), WebhookError> {
+        let signature = STANDARD
+            .decode(signature.trim())
+            .map_err(|_| WebhookError::InvalidSignature)?;
+        self.mac(body)
+            .verify_slice(&signature)
+            .map_err(|_| WebhookError::InvalidSignature)
+    }
+    /// Check the signature of a delivery and decode its body.
+    ///
+    /// The unsigned `webhook_id=<id>` ping is accepted as [`WebhookEvent::Ping`] as WooCommerce never signs it.
+    pub fn verify(
+        &self,
+        headers: WebhookHeaders,
+        body: &[u8],
+    ) -> std::result::Result<WebhookDelivery, WebhookError> {
+        if let Some(webhook_id) = ping_webhook_id(body) {
+            return Ok(WebhookDelivery {
+                headers,
+                event: WebhookEvent::Ping { webhook_id },
+            });
+        }
+        let signature = headers
+            .signature
+            .as_deref()
+            .ok_or(WebhookError::MissingHeader(SIGNATURE_HEADER))?;
+        self.check_signature(body, signature)?;
+        let event = WebhookEvent::parse(&headers, body)?;
+        Ok(WebhookDelivery { headers, event })
+    }
+    fn mac(&self, body: &[u8]) -> Hmac<sha2::Sha256> {
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(self.secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(body);
+        mac
+    }
+}
+/// Remembers delivery IDs so that deliveries retried by WooCommerce are handled once.
+///
+/// IDs are recorded once a delivery was handled, so a delivery whose handling failed is handled again when retried.
+/// Implement it over a shared store such as Redis or a database table when several instances receive webhooks.
+pub trait DeliveryStore: Send + Sync {
+    /// Whether a delivery ID was recorded
+    fn contains<'a>(&'a self, delivery_id: &'a str) -> BoxFuture<'a, Result<bool>>;
+    /// Record the ID of a handled delivery
+    fn record<'a>(&'a self, delivery_id: &'a str) -> BoxFuture<'a, Result<()>>;
+}
+/// In-process [`DeliveryStore`] keeping the most recent delivery IDs
+#[derive(Debug)]
+pub struct MemoryDeliveryStore {
+    capacity: usize,
+    seen: Mutex<(HashSet<String>, VecDeque<String>)>,
+}
+impl Default for MemoryDeliveryStore {
+    fn default() -> Self {
+        Self::new(10_000)
+    }
+}
+impl MemoryDeliveryStore {
+    /// Store remembering up to `capacity` delivery IDs, forgetting the oldest first
+    pub fn new(capacity: usize) -> Self {
+        MemoryDeliveryStore {
+            capacity: capacity.max(1),
+            seen: Mutex::new((HashSet::new(), VecDeque::new())),
+        }
+    }
+}
+impl DeliveryStore for MemoryDeliveryStore {
+    fn contains<'a>(&'a self, delivery_id: &'a str) -> BoxFuture<'a, Result<bool>> {
+        let guard = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        Box::pin(futures::future::ready(Ok(guard.0.contains(delivery_id))))
+    }
+    fn record<'a>(&'a self, delivery_id: &'a str) -> BoxFuture<'a, Result<()>> {
+        let mut guard = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        let (ids, order) = &mut *guard;
+        if ids.insert(delivery_id.to_owned()) {
+            order.push_back(delivery_id.to_owned());
+            while order.len() > self.capacity {
+                if let Some(oldest) = order.pop_front() {
+                    ids.remove(&oldest);
+                }
+            }
+        }
+        Box::pin(futures::future::ready(Ok(())))
+    }
+}
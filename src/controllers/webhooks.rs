@@ -2,13 +2,13 @@ use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::{Event, Resource, WebhookStatus};
+use crate::{Event, Resource, Topic, WebhookStatus};
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebhookCreate {
     name: Option<String>,
     status: Option<WebhookStatus>,
-    topic: String,
+    topic: Topic,
     delivery_url: String,
     secret: Option<String>,
 }
@@ -76,7 +76,7 @@ impl<R, E, D> WebhookCreateBuilder<R, E, D> {
 }
 impl WebhookCreateBuilder<WithResource, WithEvent, WithUrl> {
     pub fn build(self) -> WebhookCreate {
-        let topic = format!("{}.{}", self.resource.0, self.event.0);
+        let topic = Topic::new(self.resource.0, self.event.0);
         WebhookCreate {
             name: self.name,
             status: self.status,
@@ -92,7 +92,7 @@ pub struct WebhookUpdate {
     id: Option<i32>,
     name: Option<String>,
     status: Option<WebhookStatus>,
-    topic: Option<String>,
+    topic: Option<Topic>,
     delivery_url: Option<String>,
     secret: Option<String>,
 }
@@ -147,7 +147,7 @@ impl WebhookUpdateBuilder {
             let Some(event) = self.event else {
                 return Err(anyhow!("resource set, but event not set!"));
             };
-            let topic = Some(format!("{resource}.{event}"));
+            let topic = Some(Topic::new(resource, event));
             Ok(WebhookUpdate {
                 id: self.id,
                 name: self.name,
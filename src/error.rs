@@ -1,6 +1,250 @@
-// #[derive(Debug)]
-// pub enum Error {
-//     Custom(String),
-// }
-pub type Error = Box<dyn std::error::Error>;
+use std::fmt::Display;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+/// Result type returned by [`crate::ApiClient`] operations
 pub type Result<T> = core::result::Result<T, Error>;
+
+/// Error body returned by the WooCommerce REST API, e.g.
+/// `{"code":"woocommerce_rest_product_invalid_id","message":"Invalid ID.","data":{"status":404}}`
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct ApiError {
+    /// Machine readable error code.
+    #[serde(default)]
+    pub code: String,
+    /// Human readable error message.
+    #[serde(default)]
+    pub message: String,
+    /// Additional error data.
+    pub data: Option<ApiErrorData>,
+}
+/// Additional data attached to an [`ApiError`]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct ApiErrorData {
+    /// HTTP status reported by WooCommerce.
+    pub status: Option<u16>,
+}
+impl ApiError {
+    /// Decode an error body, falling back to the raw text as message if it is not a WooCommerce error object
+    pub(crate) fn from_body(body: &str) -> Self {
+        serde_json::from_str::<ApiError>(body).unwrap_or_else(|_| ApiError {
+            code: String::new(),
+            message: body.to_owned(),
+            data: None,
+        })
+    }
+}
+impl Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.code.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.code, self.message)
+        }
+    }
+}
+/// Errors returned by [`crate::ApiClient`]
+#[derive(Debug)]
+pub enum Error {
+    /// Request could not be sent or response could not be read.
+    Transport(reqwest::Error),
+    /// Server responded with a non-success HTTP status.
+    Status {
+        /// HTTP status of the response.
+        status: StatusCode,
+        /// Decoded WooCommerce error body.
+        body: ApiError,
+    },
+    /// Response body could not be deserialized into the expected type.
+    Deserialize {
+        /// Underlying serde error.
+        source: serde_json::Error,
+        /// Raw response body.
+        payload: String,
+    },
+    /// Server rejected the credentials (401 or 403).
+    Unauthorized {
+        /// HTTP status of the response.
+        status: StatusCode,
+        /// Decoded WooCommerce error body.
+        body: ApiError,
+    },
+    /// Requested resource does not exist (404).
+    NotFound(ApiError),
+    /// Server asked to slow down (429).
+    RateLimited {
+        /// Delay requested by the server in the `Retry-After` header.
+        retry_after: Option<Duration>,
+        /// Decoded WooCommerce error body.
+        body: ApiError,
+    },
+    /// One or more pages of a listing, or chunks of a batch request, could not be fetched.
+    Pages(Vec<PageError>),
+    /// Background request task panicked or was cancelled.
+    Task(String),
+    /// Invalid URL.
+    Url(url::ParseError),
+    /// Invalid client configuration.
+    Config(String),
+    /// Webhook delivery was rejected.
+    Webhook(WebhookError),
+    /// Local file could not be read or written.
+    Io(std::io::Error),
+    /// Recorded fixture has no response for a request.
+    Fixture(String),
+}
+impl Error {
+    /// Build an error from a non-success response
+    pub(crate) fn from_status(
+        status: StatusCode,
+        retry_after: Option<Duration>,
+        body: &str,
+    ) -> Self {
+        let body = ApiError::from_body(body);
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                Error::Unauthorized { status, body }
+            }
+            StatusCode::NOT_FOUND => Error::NotFound(body),
+            StatusCode::TOO_MANY_REQUESTS => Error::RateLimited { retry_after, body },
+            _ => Error::Status { status, body },
+        }
+    }
+    /// HTTP status of the response that caused the error, if any
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Transport(e) => e.status(),
+            Error::Status { status, .. } | Error::Unauthorized { status, .. } => Some(*status),
+            Error::NotFound(_) => Some(StatusCode::NOT_FOUND),
+            Error::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            Error::Deserialize { .. }
+            | Error::Pages(_)
+            | Error::Task(_)
+            | Error::Url(_)
+            | Error::Config(_)
+            | Error::Webhook(_)
+            | Error::Io(_)
+            | Error::Fixture(_) => None,
+        }
+    }
+    /// Decoded WooCommerce error body, if the server returned one
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Error::Status { body, .. }
+            | Error::Unauthorized { body, .. }
+            | Error::NotFound(body)
+            | Error::RateLimited { body, .. } => Some(body),
+            _ => None,
+        }
+    }
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Transport(e) => write!(f, "transport error: {e}"),
+            Error::Status { status, body } => write!(f, "request failed with {status}: {body}"),
+            Error::Deserialize { source, .. } => {
+                write!(f, "failed to deserialize response: {source}")
+            }
+            Error::Unauthorized { status, body } => write!(f, "unauthorized ({status}): {body}"),
+            Error::NotFound(body) => write!(f, "not found: {body}"),
+            Error::RateLimited { retry_after, body } => match retry_after {
+                Some(d) => write!(f, "rate limited, retry after {}s: {body}", d.as_secs()),
+                None => write!(f, "rate limited: {body}"),
+            },
+            Error::Pages(errors) => {
+                let pages = errors
+                    .iter()
+                    .map(|e| e.page.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "failed to fetch pages {pages}")?;
+                if let Some(first) = errors.first() {
+                    write!(f, ": {}", first.error)?;
+                }
+                Ok(())
+            }
+            Error::Task(e) => write!(f, "request task failed: {e}"),
+            Error::Url(e) => write!(f, "invalid url: {e}"),
+            Error::Config(e) => write!(f, "invalid configuration: {e}"),
+            Error::Webhook(e) => write!(f, "webhook rejected: {e}"),
+            Error::Io(e) => write!(f, "io error: {e}"),
+            Error::Fixture(e) => write!(f, "fixture error: {e}"),
+        }
+    }
+}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(e) => Some(e),
+            Error::Deserialize { source, .. } => Some(source),
+            Error::Pages(errors) => errors.first().map(|e| &e.error as _),
+            Error::Url(e) => Some(e),
+            Error::Webhook(e) => Some(e),
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+/// Reasons a webhook delivery is rejected by [`crate::WebhookVerifier`]
+#[derive(Debug)]
+pub enum WebhookError {
+    /// A required header is missing or is not valid UTF-8.
+    MissingHeader(&'static str),
+    /// `X-WC-Webhook-Signature` does not match the body and secret.
+    InvalidSignature,
+    /// Body could not be deserialized into the model of the topic.
+    Payload(serde_json::Error),
+}
+impl Display for WebhookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookError::MissingHeader(name) => write!(f, "missing {name} header"),
+            WebhookError::InvalidSignature => write!(f, "invalid signature"),
+            WebhookError::Payload(e) => write!(f, "invalid payload: {e}"),
+        }
+    }
+}
+impl std::error::Error for WebhookError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WebhookError::Payload(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl From<WebhookError> for Error {
+    fn from(value: WebhookError) -> Self {
+        Error::Webhook(value)
+    }
+}
+/// Failure of a single page of a paginated listing, or of a chunk of 100 objects of a batch request
+#[derive(Debug)]
+pub struct PageError {
+    /// Page or chunk number, starting from 1.
+    pub page: i32,
+    /// Error that occurred while fetching the page.
+    pub error: Error,
+}
+impl Display for PageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "page {}: {}", self.page, self.error)
+    }
+}
+impl From<reqwest::Error> for Error {
+    fn from(value: reqwest::Error) -> Self {
+        Error::Transport(value)
+    }
+}
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::Io(value)
+    }
+}
+impl From<url::ParseError> for Error {
+    fn from(value: url::ParseError) -> Self {
+        Error::Url(value)
+    }
+}
@@ -1,14 +1,186 @@
 //! # rust-woocommerce
 //!
 //! `rust-woocommerce` is a library for woocommerce API.
+//!
+//! Every resource has its own module containing the model together with its create/update builders,
+//! e.g. [`products::Product`] and [`products::ProductModify`]. Models are also re-exported from the crate root.
 
 mod controllers;
 mod models;
-pub use self::controllers::ApiClient;
+pub use self::controllers::{
+    auth::{Auth, SignatureMethod},
+    builder::{ApiClientBuilder, ApiNamespace},
+    cache::{CacheStore, CachedResponse, DiskCache, MemoryCache, ResponseCache},
+    coupons::{CouponQuery, CouponSortBy},
+    customers::{CustomerQuery, CustomerSortBy},
+    fixtures::{load_fixture, Interaction, RecordingTransport, ReplayTransport},
+    orders::{OrderQuery, OrderSortBy},
+    products::{ProductQuery, ProductSortBy},
+    query::{Query, SortOrder},
+    retry::RetryPolicy,
+    store::{StoreConfig, StorePlan},
+    stream::StreamOptions,
+    sync::{
+        Change, Checkpoint, CheckpointStore, Cursor, FileCheckpointStore, MemoryCheckpointStore,
+        SyncBatch, SyncEngine, Syncable,
+    },
+    transport::Transport,
+    webhook_receiver::{
+        DeliveryStore, MemoryDeliveryStore, WebhookDelivery, WebhookEvent, WebhookHeaders,
+        WebhookVerifier,
+    },
+    ApiClient, Entity, EntityId, PartialList,
+};
 pub use models::{
-    coupons::*, customers::*, data::*, orders::*, product_attributes::*, product_categories::*,
-    product_reviews::*, product_variations::*, products::*, reports::*, webhooks::*, BatchObject,
+    coupons::*, customers::*, data::*, money::*, order_notes::*, orders::*, payment_gateways::*,
+    product_attribute_terms::*, product_attributes::*, product_categories::*, product_reviews::*,
+    product_shipping_classes::*, product_variations::*, products::*, refunds::*, reports::*,
+    settings::*, shipping_methods::*, shipping_zone_locations::*, shipping_zone_methods::*,
+    shipping_zones::*, tax_classes::*, tax_rates::*, webhooks::*, BatchObject, BatchObjectBuilder,
     MetaData,
 };
+/// Coupons and their create/update builders.
+pub mod coupons {
+    pub use crate::controllers::coupons::*;
+    pub use crate::models::coupons::*;
+}
+/// Customers and their create/update payloads.
+pub mod customers {
+    pub use crate::controllers::customers::*;
+    pub use crate::models::customers::*;
+}
+/// Continents, countries and currencies.
+pub mod data {
+    pub use crate::models::data::*;
+}
+/// Order notes and their create/update builders.
+pub mod order_notes {
+    pub use crate::controllers::order_notes::*;
+    pub use crate::models::order_notes::*;
+}
+/// Orders, their create/update builders and list query.
+pub mod orders {
+    pub use crate::controllers::orders::*;
+    pub use crate::models::orders::*;
+}
+/// Payment gateways and their update payload.
+pub mod payment_gateways {
+    pub use crate::controllers::payment_gateways::*;
+    pub use crate::models::payment_gateways::*;
+}
+/// Product attribute terms and their create/update builders.
+pub mod product_attribute_terms {
+    pub use crate::controllers::product_attribute_terms::*;
+    pub use crate::models::product_attribute_terms::*;
+}
+/// Product attributes and their create/update builders.
+pub mod product_attributes {
+    pub use crate::controllers::product_attributes::*;
+    pub use crate::models::product_attributes::*;
+}
+/// Product categories and their create/update builders.
+pub mod product_categories {
+    pub use crate::controllers::product_categories::*;
+    pub use crate::models::product_categories::*;
+}
+/// Product reviews and their create/update builders.
+pub mod product_reviews {
+    pub use crate::controllers::product_reviews::*;
+    pub use crate::models::product_reviews::*;
+}
+/// Product shipping classes and their create/update builders.
+pub mod product_shipping_classes {
+    pub use crate::controllers::product_shipping_classes::*;
+    pub use crate::models::product_shipping_classes::*;
+}
+/// Product tags and their create/update builders.
+pub mod product_tags {
+    pub use crate::controllers::product_tags::*;
+    pub use crate::models::product_tags::*;
+}
+/// Product variations and their create/update builder.
+pub mod product_variations {
+    pub use crate::controllers::product_variations::*;
+    pub use crate::models::product_variations::*;
+}
+/// Products, their create/update builder and list query.
+pub mod products {
+    pub use crate::controllers::products::*;
+    pub use crate::models::products::*;
+}
+/// Order refunds and their create builders.
+pub mod refunds {
+    pub use crate::controllers::refunds::*;
+    pub use crate::models::refunds::*;
+}
+/// Sales, top sellers and totals reports.
+pub mod reports {
+    pub use crate::models::reports::*;
+}
+/// Setting groups and setting option updates.
+pub mod settings {
+    pub use crate::controllers::settings::*;
+    pub use crate::models::settings::*;
+}
+/// Shipping methods.
+pub mod shipping_methods {
+    pub use crate::models::shipping_methods::*;
+}
+/// Shipping zone locations.
+pub mod shipping_zone_locations {
+    pub use crate::models::shipping_zone_locations::*;
+}
+/// Shipping zone methods and their include/update payloads.
+pub mod shipping_zone_methods {
+    pub use crate::controllers::shipping_zone_methods::*;
+    pub use crate::models::shipping_zone_methods::*;
+}
+/// Shipping zones, their create/update payloads and declarative configuration.
+pub mod shipping_zones {
+    pub use crate::controllers::shipping_zones::*;
+    pub use crate::models::shipping_zones::*;
+}
+/// Declarative store configuration and the plan to apply it.
+pub mod store {
+    pub use crate::controllers::store::*;
+}
+/// Incremental mirroring of products, orders and customers.
+pub mod sync {
+    pub use crate::controllers::sync::*;
+}
+/// Strict deserialization of the models, for tests that should catch WooCommerce quirks and unknown fields.
+///
+/// Models normally tolerate what WooCommerce and its plugins send in practice, such as `false` for an unset string
+/// or fields this crate does not model. The functions here, and clients built with [`ApiClientBuilder::strict`],
+/// reject those instead.
+pub mod strict {
+    pub use crate::models::strict::{from_slice, from_str, from_value};
+}
+/// In-process mock WooCommerce server for tests, enabled by the `testing` feature.
+#[cfg(feature = "testing")]
+pub mod testing;
+/// Tax classes and their create builder.
+pub mod tax_classes {
+    pub use crate::controllers::tax_classes::*;
+    pub use crate::models::tax_classes::*;
+}
+/// Tax rates and their create/update builders.
+pub mod tax_rates {
+    pub use crate::controllers::tax_rates::*;
+    pub use crate::models::tax_rates::*;
+}
+/// Webhooks and their create/update builders.
+pub mod webhooks {
+    #[cfg(feature = "axum")]
+    pub use crate::controllers::webhook_axum::*;
+    pub use crate::controllers::webhook_receiver::*;
+    pub use crate::controllers::webhooks::*;
+    pub use crate::models::webhooks::*;
+}
 mod config;
+mod error;
 pub use config::Config;
+#[cfg(feature = "axum")]
+pub use controllers::webhook_axum::{VerifiedWebhook, WebhookReceiver, WebhookRejection};
+pub use error::{ApiError, ApiErrorData, Error, PageError, Result, WebhookError};
+pub use rust_decimal::Decimal;
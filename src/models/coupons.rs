@@ -1,36 +1,47 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::money::Money;
+
 use crate::controllers::coupons::{
-    CreateCouponBuilder, NoAmount, NoCode, NoDiscountType, UpdateCouponBuilder,
+    CouponQuery, CreateCouponBuilder, NoAmount, NoCode, NoDiscountType, UpdateCouponBuilder,
 };
 use crate::controllers::Entity;
 
-use super::MetaData;
+use super::{empty_as_none, extra_fields, lenient_string, MetaData};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Coupon {
     /// Unique identifier for the object.
     pub id: i32,
     /// Coupon code.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub code: String,
     /// The amount of discount. Should always be numeric, even if setting a percentage.
-    pub amount: String,
+    #[serde(default)]
+    pub amount: Money,
     /// The date the coupon was created, in the site's timezone.
-    pub date_created: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_created: Option<NaiveDateTime>,
     /// The date the coupon was created, as GMT.
-    pub date_created_gmt: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_created_gmt: Option<NaiveDateTime>,
     /// The date the coupon was last modified, in the site's timezone.
-    pub date_modified: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_modified: Option<NaiveDateTime>,
     /// The date the coupon was last modified, as GMT.
-    pub date_modified_gmt: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_modified_gmt: Option<NaiveDateTime>,
     /// Determines the type of discount that will be applied. Options: percent, fixed_cart and fixed_product. Default is fixed_cart.
     pub discount_type: DiscountType,
     /// Coupon description.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub description: String,
     /// The date the coupon expires, in the site's timezone.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_expires: Option<String>,
     /// The date the coupon expires, as GMT.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_expires_gmt: Option<String>,
     /// Number of times the coupon has been used already.
     pub usage_count: i32,
@@ -41,10 +52,13 @@ pub struct Coupon {
     /// List of product IDs the coupon cannot be used on.
     pub excluded_product_ids: Vec<i32>,
     /// How many times the coupon can be used in total.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub usage_limit: Option<i32>,
     /// How many times the coupon can be used per customer.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub usage_limit_per_user: Option<i32>,
     /// Max number of items in the cart the coupon can be applied to.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub limit_usage_to_x_items: Option<i32>,
     /// If true and if the free shipping method requires a coupon, this coupon will enable free shipping. Default is false.
     pub free_shipping: bool,
@@ -55,17 +69,23 @@ pub struct Coupon {
     /// If true, this coupon will not be applied to items that have sale prices. Default is false.
     pub exclude_sale_items: bool,
     /// Minimum order amount that needs to be in the cart before coupon applies.
-    pub minimum_amount: String,
+    #[serde(default)]
+    pub minimum_amount: Money,
     /// Maximum order amount allowed when using the coupon.
-    pub maximum_amount: String,
+    #[serde(default)]
+    pub maximum_amount: Money,
     /// List of email addresses that can use this coupon.
     pub email_restrictions: Vec<String>,
     /// List of user IDs (or guest email addresses) that have used the coupon.
     pub used_by: Vec<String>,
     /// Meta data.
     pub meta_data: Vec<MetaData>,
+    /// Fields not modelled by this crate, e.g. added by plugins.
+    #[serde(flatten, deserialize_with = "extra_fields")]
+    pub extra: serde_json::Value,
 }
 impl Entity for Coupon {
+    type Id = i32;
     fn endpoint() -> String {
         String::from("coupons/")
     }
@@ -81,13 +101,41 @@ impl Coupon {
     pub fn update() -> UpdateCouponBuilder {
         UpdateCouponBuilder::default()
     }
+    pub fn query() -> CouponQuery {
+        CouponQuery::default()
+    }
+    /// The date the coupon was created, from `date_created_gmt`
+    pub fn date_created_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_created_gmt.map(|date| date.and_utc())
+    }
+    /// The date the coupon was last modified, from `date_modified_gmt`
+    pub fn date_modified_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_modified_gmt.map(|date| date.and_utc())
+    }
+    /// The date the coupon expires, from `date_expires_gmt`
+    pub fn date_expires_utc(&self) -> Option<DateTime<Utc>> {
+        let date = self.date_expires_gmt.as_deref()?;
+        date.parse::<NaiveDateTime>()
+            .or_else(|_| {
+                date.parse::<NaiveDate>()
+                    .map(|d| d.and_time(NaiveTime::MIN))
+            })
+            .ok()
+            .map(|date| date.and_utc())
+    }
 }
 /// Determines the type of discount that will be applied.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(from = "String", into = "String")]
 pub enum DiscountType {
     #[default]
     FixedCart,
     FixedProduct,
     Percent,
+    Other(String),
 }
+string_enum!(DiscountType {
+    FixedCart => "fixed_cart",
+    FixedProduct => "fixed_product",
+    Percent => "percent",
+});
@@ -1,29 +1,39 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::controllers::customers::{CreateCustomer, UpdateCustomer};
+use crate::controllers::customers::{CreateCustomer, CustomerQuery, UpdateCustomer};
 use crate::controllers::Entity;
 
-use super::MetaData;
+use super::{empty_as_none, extra_fields, lenient_string, MetaData};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Customer {
     /// Unique identifier for the resource
     pub id: i32,
     /// The date the customer was created, in the site's timezone.
-    pub date_created: NaiveDateTime,
-    /// The date the customer was created, as GMT
-    pub date_modified: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_created: Option<NaiveDateTime>,
+    /// The date the customer was created, as GMT.
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_created_gmt: Option<NaiveDateTime>,
+    /// The date the customer was last modified, in the site's timezone.
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_modified: Option<NaiveDateTime>,
     /// The date the customer was last modified, as GMT.
-    pub date_modified_gmt: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_modified_gmt: Option<NaiveDateTime>,
     /// The email address for the customer
+    #[serde(default, deserialize_with = "lenient_string")]
     pub email: String,
     /// Customer first name.    
+    #[serde(default, deserialize_with = "lenient_string")]
     pub first_name: String,
     /// Customer last name.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub last_name: String,
     /// Customer role.
     pub role: Role,
     /// Customer login name.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub username: String,
     /// List of billing address data.    
     pub billing: Billing,
@@ -32,11 +42,16 @@ pub struct Customer {
     /// Is the customer a paying customer
     pub is_paying_customer: bool,
     /// Avatar URL
+    #[serde(default, deserialize_with = "lenient_string")]
     pub avatar_url: String,
     /// Meta data.
     pub meta_data: Vec<MetaData>,
+    /// Fields not modelled by this crate, e.g. added by plugins.
+    #[serde(flatten, deserialize_with = "extra_fields")]
+    pub extra: serde_json::Value,
 }
 impl Entity for Customer {
+    type Id = i32;
     fn endpoint() -> String {
         String::from("customers/")
     }
@@ -92,8 +107,8 @@ pub struct Shipping {
     pub country: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(from = "String", into = "String")]
 pub enum Role {
     Administrator,
     Editor,
@@ -103,7 +118,17 @@ pub enum Role {
     #[default]
     Customer,
     ShopManager,
+    Other(String),
 }
+string_enum!(Role {
+    Administrator => "administrator",
+    Editor => "editor",
+    Author => "author",
+    Contributor => "contributor",
+    Subscriber => "subscriber",
+    Customer => "customer",
+    ShopManager => "shop_manager",
+});
 impl Customer {
     pub fn create<T: ToString>(email: T) -> CreateCustomer {
         CreateCustomer::new(email)
@@ -111,4 +136,15 @@ impl Customer {
     pub fn update() -> UpdateCustomer {
         UpdateCustomer::default()
     }
+    pub fn query() -> CustomerQuery {
+        CustomerQuery::default()
+    }
+    /// The date the customer was created, from `date_created_gmt`
+    pub fn date_created_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_created_gmt.map(|date| date.and_utc())
+    }
+    /// The date the customer was last modified, from `date_modified_gmt`
+    pub fn date_modified_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_modified_gmt.map(|date| date.and_utc())
+    }
 }
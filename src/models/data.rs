@@ -10,6 +10,7 @@ pub struct Data {
 }
 
 impl Entity for Data {
+    type Id = String;
     fn endpoint() -> String {
         String::from("data/")
     }
@@ -27,6 +28,16 @@ pub struct Continent {
     /// List of countries on this continent.
     pub countries: Vec<Country>,
 }
+impl Entity for Continent {
+    type Id = String;
+    fn endpoint() -> String {
+        String::from("data/continents/")
+    }
+    fn child_endpoint(parent_id: i32) -> String {
+        let _ = parent_id;
+        String::new()
+    }
+}
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Country {
     /// ISO3166 alpha-2 country code    
@@ -66,7 +77,18 @@ pub struct Currency {
     /// Currency symbol.
     pub symbol: String,
 }
+impl Entity for Currency {
+    type Id = String;
+    fn endpoint() -> String {
+        String::from("data/currencies/")
+    }
+    fn child_endpoint(parent_id: i32) -> String {
+        let _ = parent_id;
+        String::new()
+    }
+}
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd, Default)]
+#[serde(from = "String", into = "String")]
 pub enum CurrencyISO {
     AED,
     AFN,
@@ -234,4 +256,172 @@ pub enum CurrencyISO {
     YER,
     ZAR,
     ZMW,
+    Other(String),
 }
+string_enum!(CurrencyISO {
+    AED => "AED",
+    AFN => "AFN",
+    ALL => "ALL",
+    AMD => "AMD",
+    ANG => "ANG",
+    AOA => "AOA",
+    ARS => "ARS",
+    AUD => "AUD",
+    AWG => "AWG",
+    AZN => "AZN",
+    BAM => "BAM",
+    BYN => "BYN",
+    BBD => "BBD",
+    BDT => "BDT",
+    BGN => "BGN",
+    BHD => "BHD",
+    BIF => "BIF",
+    BMD => "BMD",
+    BND => "BND",
+    BOB => "BOB",
+    BRL => "BRL",
+    BSD => "BSD",
+    BTC => "BTC",
+    BTN => "BTN",
+    BWP => "BWP",
+    BYR => "BYR",
+    BZD => "BZD",
+    CAD => "CAD",
+    CDF => "CDF",
+    CHF => "CHF",
+    CLP => "CLP",
+    CNY => "CNY",
+    COP => "COP",
+    CRC => "CRC",
+    CUC => "CUC",
+    CUP => "CUP",
+    CVE => "CVE",
+    CZK => "CZK",
+    DJF => "DJF",
+    DKK => "DKK",
+    DOP => "DOP",
+    DZD => "DZD",
+    EGP => "EGP",
+    ERN => "ERN",
+    ETB => "ETB",
+    EUR => "EUR",
+    FJD => "FJD",
+    FKP => "FKP",
+    GBP => "GBP",
+    GEL => "GEL",
+    GGP => "GGP",
+    GHS => "GHS",
+    GIP => "GIP",
+    GMD => "GMD",
+    GNF => "GNF",
+    GTQ => "GTQ",
+    GYD => "GYD",
+    HKD => "HKD",
+    HNL => "HNL",
+    HRK => "HRK",
+    HTG => "HTG",
+    HUF => "HUF",
+    IDR => "IDR",
+    ILS => "ILS",
+    IMP => "IMP",
+    INR => "INR",
+    IQD => "IQD",
+    IRR => "IRR",
+    IRT => "IRT",
+    ISK => "ISK",
+    JEP => "JEP",
+    JMD => "JMD",
+    JOD => "JOD",
+    JPY => "JPY",
+    KES => "KES",
+    KGS => "KGS",
+    KHR => "KHR",
+    KMF => "KMF",
+    KPW => "KPW",
+    KRW => "KRW",
+    KWD => "KWD",
+    KYD => "KYD",
+    KZT => "KZT",
+    LAK => "LAK",
+    LBP => "LBP",
+    LKR => "LKR",
+    LRD => "LRD",
+    LSL => "LSL",
+    LYD => "LYD",
+    MAD => "MAD",
+    MDL => "MDL",
+    MGA => "MGA",
+    MKD => "MKD",
+    MMK => "MMK",
+    MNT => "MNT",
+    MOP => "MOP",
+    MRO => "MRO",
+    MRU => "MRU",
+    MUR => "MUR",
+    MVR => "MVR",
+    MWK => "MWK",
+    MXN => "MXN",
+    MYR => "MYR",
+    MZN => "MZN",
+    NAD => "NAD",
+    NGN => "NGN",
+    NIO => "NIO",
+    NOK => "NOK",
+    NPR => "NPR",
+    NZD => "NZD",
+    OMR => "OMR",
+    PAB => "PAB",
+    PEN => "PEN",
+    PGK => "PGK",
+    PHP => "PHP",
+    PKR => "PKR",
+    PLN => "PLN",
+    PRB => "PRB",
+    PYG => "PYG",
+    QAR => "QAR",
+    RON => "RON",
+    RSD => "RSD",
+    RUB => "RUB",
+    RWF => "RWF",
+    SAR => "SAR",
+    SBD => "SBD",
+    SCR => "SCR",
+    SDG => "SDG",
+    SEK => "SEK",
+    SGD => "SGD",
+    SHP => "SHP",
+    SLL => "SLL",
+    SOS => "SOS",
+    SRD => "SRD",
+    SSP => "SSP",
+    STD => "STD",
+    STN => "STN",
+    SYP => "SYP",
+    SZL => "SZL",
+    THB => "THB",
+    TJS => "TJS",
+    TMT => "TMT",
+    TND => "TND",
+    TOP => "TOP",
+    TRY => "TRY",
+    TTD => "TTD",
+    TWD => "TWD",
+    TZS => "TZS",
+    UAH => "UAH",
+    UGX => "UGX",
+    USD => "USD",
+    UYU => "UYU",
+    UZS => "UZS",
+    VEF => "VEF",
+    VES => "VES",
+    VND => "VND",
+    VUV => "VUV",
+    WST => "WST",
+    XAF => "XAF",
+    XCD => "XCD",
+    XOF => "XOF",
+    XPF => "XPF",
+    YER => "YER",
+    ZAR => "ZAR",
+    ZMW => "ZMW",
+});
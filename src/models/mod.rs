@@ -1,8 +1,55 @@
+use serde::{de::DeserializeOwned, de::IgnoredAny, Deserialize, Deserializer};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
+use strict::is_strict;
 
+/// Implements lossless `String` conversions and `Display` for an enum with an `Other(String)` variant.
+///
+/// Enums using it are (de)serialized with `#[serde(from = "String", into = "String")]`,
+/// so values added by plugins or newer WooCommerce versions end up in `Other` instead of failing the whole response.
+macro_rules! string_enum {
+    ($name:ident { $($variant:ident => $value:literal),* $(,)? }) => {
+        impl $name {
+            /// Value as sent by the WooCommerce API
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Other(other) => other,
+                }
+            }
+        }
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $($value => $name::$variant,)*
+                    _ => $name::Other(value),
+                }
+            }
+        }
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                $name::from(value.to_owned())
+            }
+        }
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                match value {
+                    $name::Other(other) => other,
+                    known => known.as_str().to_owned(),
+                }
+            }
+        }
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
 pub mod coupons;
 pub mod customers;
 pub mod data;
+pub mod money;
 pub mod order_notes;
 pub mod orders;
 pub mod payment_gateways;
@@ -21,6 +68,7 @@ pub mod shipping_methods;
 pub mod shipping_zone_locations;
 pub mod shipping_zone_methods;
 pub mod shipping_zones;
+pub mod strict;
 pub mod tax_classes;
 pub mod tax_rates;
 pub mod webhooks;
@@ -103,3 +151,95 @@ where
         }
     }
 }
+/// PHP encodes an empty associative array as `[]`, read it as the default (empty) map
+pub(crate) fn empty_array_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum MapOrArray<T> {
+        Map(T),
+        Array(Vec<IgnoredAny>),
+    }
+    match MapOrArray::<T>::deserialize(deserializer)? {
+        MapOrArray::Map(map) => Ok(map),
+        MapOrArray::Array(array) if array.is_empty() => Ok(T::default()),
+        MapOrArray::Array(_) => Err(serde::de::Error::custom("expected a map or an empty array")),
+    }
+}
+/// Read a value WooCommerce sends either as a string or as a number into a string
+pub(crate) fn string_or_number<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => Ok(s),
+        serde_json::Value::Number(n) => Ok(n.to_string()),
+        serde_json::Value::Null => Ok(String::new()),
+        other => Err(serde::de::Error::custom(format!(
+            "expected a string or a number, found {other}"
+        ))),
+    }
+}
+// Lenient readers below accept WooCommerce quirks unless the models are read in strict mode, see [`strict`]
+/// Read a string WooCommerce or a plugin may send as `null`, `false` or a number
+pub(crate) fn lenient_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::String(s) => Ok(s),
+        Value::Null | Value::Bool(false) if !is_strict() => Ok(String::new()),
+        Value::Number(n) if !is_strict() => Ok(n.to_string()),
+        other => Err(serde::de::Error::custom(format!(
+            "expected a string, found {other}"
+        ))),
+    }
+}
+/// Read an optional value WooCommerce sends as `""`, `false` or `[]` when unset, or a number sent as a string
+pub(crate) fn empty_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let value = Value::deserialize(deserializer)?;
+    match &value {
+        Value::Null => return Ok(None),
+        // Core WooCommerce itself sends unset values such as `sale_price` as `""`, so it is not a quirk
+        Value::String(s) if s.is_empty() => return Ok(None),
+        Value::Bool(false) if !is_strict() => return Ok(None),
+        Value::Array(a) if a.is_empty() && !is_strict() => return Ok(None),
+        _ => {}
+    }
+    match T::deserialize(&value) {
+        Ok(parsed) => Ok(Some(parsed)),
+        Err(e) => match &value {
+            Value::String(s) if !is_strict() => serde_json::from_str(s)
+                .map(Some)
+                .map_err(|_| serde::de::Error::custom(e)),
+            _ => Err(serde::de::Error::custom(e)),
+        },
+    }
+}
+/// Keys the REST API adds around every entity, accepted even in strict mode
+const ENVELOPE_FIELDS: [&str; 2] = ["_links", "_embedded"];
+/// Collect fields this crate does not model, in strict mode they are an error instead
+pub(crate) fn extra_fields<'de, D>(deserializer: D) -> Result<Value, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let extra = serde_json::Map::deserialize(deserializer)?;
+    let mut unknown = extra
+        .keys()
+        .filter(|key| !ENVELOPE_FIELDS.contains(&key.as_str()))
+        .peekable();
+    if is_strict() && unknown.peek().is_some() {
+        let fields = unknown.cloned().collect::<Vec<_>>().join(", ");
+        return Err(serde::de::Error::custom(format!(
+            "unknown fields: {fields}"
+        )));
+    }
+    Ok(Value::Object(extra))
+}
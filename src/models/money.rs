@@ -0,0 +1,194 @@
+use std::fmt::Display;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use super::{
+    data::{Country, CurrencyISO},
+    strict::is_strict,
+};
+
+/// Exact decimal amount of money.
+///
+/// WooCommerce sends prices and totals as strings such as `"10.50"`, they are parsed without going through floats
+/// and written back as strings keeping their scale. An empty string reads as zero, except in [strict mode](crate::strict).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Money(Decimal);
+impl Money {
+    /// Zero amount
+    pub const ZERO: Money = Money(Decimal::ZERO);
+    /// Amount of `num * 10^-scale`, e.g. `Money::new(1050, 2)` is 10.50
+    pub fn new(num: i64, scale: u32) -> Self {
+        Money(Decimal::new(num, scale))
+    }
+    /// Decimal value of the amount
+    pub fn amount(&self) -> Decimal {
+        self.0
+    }
+    /// Returns true if the amount is zero
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+    /// Amount rounded half away from zero to the given number of decimals, the way WooCommerce rounds prices
+    pub fn round(&self, decimals: u32) -> Self {
+        Money(
+            self.0
+                .round_dp_with_strategy(decimals, RoundingStrategy::MidpointAwayFromZero),
+        )
+    }
+    /// Amount formatted for display with the separators, number of decimals and currency position of a country.
+    ///
+    /// The currency is shown by its ISO code, use [`Money::format_with_symbol`] to show a symbol instead.
+    pub fn format(&self, currency: &CurrencyISO, country: &Country) -> String {
+        self.format_with_symbol(currency.as_str(), country)
+    }
+    /// Amount formatted for display with the separators, number of decimals and currency position of a country
+    pub fn format_with_symbol(&self, symbol: &str, country: &Country) -> String {
+        let decimals = country.num_decimals.unwrap_or(2).max(0) as u32;
+        let decimal_sep = country.decimal_sep.as_deref().unwrap_or(".");
+        let thousand_sep = country.thousand_sep.as_deref().unwrap_or(",");
+        let rounded = self.round(decimals).0.abs();
+        let digits = format!("{:.*}", decimals as usize, rounded);
+        let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+        let mut number = String::new();
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                number.push_str(thousand_sep);
+            }
+            number.push(digit);
+        }
+        if !fraction.is_empty() {
+            number.push_str(decimal_sep);
+            number.push_str(fraction);
+        }
+        let sign = if self.0.is_sign_negative() && !rounded.is_zero() {
+            "-"
+        } else {
+            ""
+        };
+        match country.currency_pos.as_deref() {
+            Some("right") => format!("{sign}{number}{symbol}"),
+            Some("left_space") => format!("{sign}{symbol} {number}"),
+            Some("right_space") => format!("{sign}{number} {symbol}"),
+            _ => format!("{sign}{symbol}{number}"),
+        }
+    }
+}
+impl Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+impl FromStr for Money {
+    type Err = rust_decimal::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() && !is_strict() {
+            return Ok(Money::ZERO);
+        }
+        Decimal::from_str_exact(s)
+            .or_else(|_| Decimal::from_scientific(s))
+            .map(Money)
+    }
+}
+impl From<Decimal> for Money {
+    fn from(value: Decimal) -> Self {
+        Money(value)
+    }
+}
+impl From<Money> for Decimal {
+    fn from(value: Money) -> Self {
+        value.0
+    }
+}
+impl From<i64> for Money {
+    fn from(value: i64) -> Self {
+        Money(Decimal::from(value))
+    }
+}
+impl From<i32> for Money {
+    fn from(value: i32) -> Self {
+        Money(Decimal::from(value))
+    }
+}
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let parsed = match Value::deserialize(deserializer)? {
+            Value::String(s) => s.parse(),
+            // serde_json keeps the shortest representation of the number, which is what the store sent
+            Value::Number(n) => n.to_string().parse(),
+            Value::Null | Value::Bool(false) if !is_strict() => Ok(Money::ZERO),
+            other => {
+                return Err(serde::de::Error::custom(format!(
+                    "expected an amount, found {other}"
+                )))
+            }
+        };
+        parsed.map_err(serde::de::Error::custom)
+    }
+}
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, rhs: Money) -> Money {
+        Money(self.0 + rhs.0)
+    }
+}
+impl AddAssign for Money {
+    fn add_assign(&mut self, rhs: Money) {
+        self.0 += rhs.0;
+    }
+}
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, rhs: Money) -> Money {
+        Money(self.0 - rhs.0)
+    }
+}
+impl SubAssign for Money {
+    fn sub_assign(&mut self, rhs: Money) {
+        self.0 -= rhs.0;
+    }
+}
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money(-self.0)
+    }
+}
+impl<T: Into<Decimal>> Mul<T> for Money {
+    type Output = Money;
+
+    fn mul(self, rhs: T) -> Money {
+        Money(self.0 * rhs.into())
+    }
+}
+impl<T: Into<Decimal>> Div<T> for Money {
+    type Output = Money;
+
+    fn div(self, rhs: T) -> Money {
+        Money(self.0 / rhs.into())
+    }
+}
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
+impl<'a> Sum<&'a Money> for Money {
+    fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Money {
+        iter.copied().sum()
+    }
+}
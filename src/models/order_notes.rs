@@ -1,5 +1,5 @@
 use crate::controllers::Entity;
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::controllers::order_notes::{
@@ -29,8 +29,13 @@ impl OrderNotes {
     pub fn update() -> OrderNotesUpdateBuilder<NoId> {
         OrderNotesUpdateBuilder::<NoId>::new()
     }
+    /// The date the note was created, from `date_created_gmt`
+    pub fn date_created_utc(&self) -> DateTime<Utc> {
+        self.date_created_gmt.and_utc()
+    }
 }
 impl Entity for OrderNotes {
+    type Id = i32;
     fn endpoint() -> String {
         String::new()
     }
@@ -1,84 +1,113 @@
-use crate::controllers::orders::{CreateOrderBuilder, UpdateOrderBuilder};
+use crate::controllers::orders::{CreateOrderBuilder, OrderQuery, UpdateOrderBuilder};
 
 use super::{
     customers::{Billing, Shipping},
     data::CurrencyISO,
-    MetaData,
+    empty_as_none, extra_fields, lenient_string, MetaData,
 };
 use crate::controllers::Entity;
 use crate::TaxStatus;
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::money::Money;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Order {
     /// Unique identifier for the resource.
     pub id: i32,
     /// Parent order ID.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub parent_id: Option<i32>,
     /// Order number.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub number: String,
     /// Order key.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub order_key: String,
     /// Shows where the order was created.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub created_via: String,
     /// Version of WooCommerce which last updated the order.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub version: String,
     /// Order status.
     pub status: OrderStatus,
     /// Currency the order was created with, in ISO format.
     pub currency: CurrencyISO,
     /// The date the order was created, in the site's timezone.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_created: Option<NaiveDateTime>,
     /// The date the order was created, as GMT.    
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_created_gmt: Option<NaiveDateTime>,
     ///The date the order was last modified, in the site's timezone.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_modified: Option<NaiveDateTime>,
     /// The date the order was last modified, as GMT
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_modified_gmt: Option<NaiveDateTime>,
     /// Total discount amount for the order.
-    pub discount_total: String,
+    #[serde(default)]
+    pub discount_total: Money,
     /// Total discount tax amount for the order.
-    pub discount_tax: String,
+    #[serde(default)]
+    pub discount_tax: Money,
     /// Total shipping amount for the order.
-    pub shipping_total: String,
+    #[serde(default)]
+    pub shipping_total: Money,
     /// Total shipping tax amount for the order.
-    pub shipping_tax: String,
+    #[serde(default)]
+    pub shipping_tax: Money,
     /// Sum of line item taxes only.
-    pub cart_tax: String,
+    #[serde(default)]
+    pub cart_tax: Money,
     /// Grand total.
-    pub total: String,
+    #[serde(default)]
+    pub total: Money,
     /// Sum of all taxes.
-    pub total_tax: String,
+    #[serde(default)]
+    pub total_tax: Money,
     /// True the prices included tax during checkout.
     pub prices_include_tax: bool,
     /// User ID who owns the order. 0 for guests. Default is 0.
     pub customer_id: i32,
     /// Customer's IP address.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub customer_ip_address: String,
     /// User agent of the customer.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub customer_user_agent: String,
     /// Note left by customer during checkout.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub customer_note: String,
     /// Billing address.
     pub billing: Billing,
     /// Shipping address.
     pub shipping: Shipping,
     /// Payment method ID.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub payment_method: String,
     /// Payment method title.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub payment_method_title: String,
     /// Unique transaction ID.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub transaction_id: String,
     /// The date the order was paid, in the site's timezone.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_paid: Option<NaiveDateTime>,
     /// The date the order was paid, as GMT.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_paid_gmt: Option<NaiveDateTime>,
     /// The date the order was completed, in the site's timezone.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_completed: Option<NaiveDateTime>,
     /// The date the order was completed, as GMT.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_completed_gmt: Option<NaiveDateTime>,
     /// MD5 hash of cart items to ensure orders are not modified.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub cart_hash: String,
     /// Meta data.
     pub meta_data: Vec<MetaData>,
@@ -96,6 +125,9 @@ pub struct Order {
     pub refunds: Vec<OrderRefundProperties>,
     // Define if the order is paid. It will set the status to processing and reduce stock items. Default is false.
     // pub set_paid: bool,
+    /// Fields not modelled by this crate, e.g. added by plugins.
+    #[serde(flatten, deserialize_with = "extra_fields")]
+    pub extra: serde_json::Value,
 }
 impl Order {
     pub fn create() -> CreateOrderBuilder {
@@ -104,8 +136,28 @@ impl Order {
     pub fn update() -> UpdateOrderBuilder {
         UpdateOrderBuilder::default()
     }
+    pub fn query() -> OrderQuery {
+        OrderQuery::default()
+    }
+    /// The date the order was created, from `date_created_gmt`
+    pub fn date_created_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_created_gmt.map(|date| date.and_utc())
+    }
+    /// The date the order was last modified, from `date_modified_gmt`
+    pub fn date_modified_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_modified_gmt.map(|date| date.and_utc())
+    }
+    /// The date the order was paid, from `date_paid_gmt`
+    pub fn date_paid_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_paid_gmt.map(|date| date.and_utc())
+    }
+    /// The date the order was completed, from `date_completed_gmt`
+    pub fn date_completed_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_completed_gmt.map(|date| date.and_utc())
+    }
 }
 impl Entity for Order {
+    type Id = i32;
     fn endpoint() -> String {
         String::from("orders/")
     }
@@ -115,7 +167,7 @@ impl Entity for Order {
     }
 }
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-#[serde(rename_all = "kebab-case")]
+#[serde(from = "String", into = "String")]
 pub enum OrderStatus {
     #[default]
     Pending,
@@ -127,7 +179,19 @@ pub enum OrderStatus {
     Failed,
     Trash,
     Draft,
+    Other(String),
 }
+string_enum!(OrderStatus {
+    Pending => "pending",
+    Processing => "processing",
+    OnHold => "on-hold",
+    Completed => "completed",
+    Cancelled => "cancelled",
+    Refunded => "refunded",
+    Failed => "failed",
+    Trash => "trash",
+    Draft => "draft",
+});
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderLineItemProperties {
     /// Item ID.
@@ -143,13 +207,13 @@ pub struct OrderLineItemProperties {
     /// Slug of the tax class of product.
     pub tax_class: String,
     /// Line subtotal (before discounts).
-    pub subtotal: String,
+    pub subtotal: Money,
     /// Line subtotal tax (before discounts).
-    pub subtotal_tax: String,
+    pub subtotal_tax: Money,
     /// Line total (after discounts).
-    pub total: String,
+    pub total: Money,
     /// Line total tax (after discounts).
-    pub total_tax: String,
+    pub total_tax: Money,
     /// Line taxes.
     pub taxes: Vec<OrderTax>,
     /// Meta data.
@@ -157,7 +221,7 @@ pub struct OrderLineItemProperties {
     /// Product SKU.
     pub sku: Option<String>,
     /// Product price.
-    pub price: f64,
+    pub price: Money,
 }
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderTaxLineProperties {
@@ -172,9 +236,9 @@ pub struct OrderTaxLineProperties {
     /// Show if is a compound tax rate.
     pub compound: bool,
     /// Tax total (not including shipping taxes).
-    pub tax_total: String,
+    pub tax_total: Money,
     /// Shipping tax total.
-    pub shipping_tax_total: String,
+    pub shipping_tax_total: Money,
     /// Meta data.
     pub meta_data: Vec<MetaData>,
 }
@@ -187,9 +251,9 @@ pub struct ShippingLineProperties {
     /// Shipping method ID.
     pub method_id: String,
     /// Line total (after discounts).
-    pub total: String,
+    pub total: Money,
     /// Line total tax (after discounts).
-    pub total_tax: String,
+    pub total_tax: Money,
     /// Line taxes.
     pub taxes: Vec<OrderTax>,
     /// Meta data.
@@ -206,9 +270,9 @@ pub struct OrderFeeLineProperties {
     /// Tax status of fee. Options: taxable and none.
     pub tax_status: TaxStatus,
     /// Line total (after discounts).
-    pub total: String,
+    pub total: Money,
     /// Line total tax (after discounts).
-    pub total_tax: String,
+    pub total_tax: Money,
     /// Line taxes.
     pub taxes: Vec<OrderTax>,
     /// Meta data.
@@ -222,9 +286,9 @@ pub struct OrderCouponLineProperties {
     /// Coupon code.
     pub code: String,
     /// Discount total.
-    pub discount: String,
+    pub discount: Money,
     /// Discount total tax.
-    pub discount_tax: String,
+    pub discount_tax: Money,
     /// Meta data.
     pub meta_data: Vec<MetaData>,
 }
@@ -235,7 +299,7 @@ pub struct OrderRefundProperties {
     /// Refund reason.
     pub reason: String,
     /// Refund total.
-    pub total: String,
+    pub total: Money,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -251,9 +315,9 @@ pub struct OrderTax {
     /// Show if is a compound tax rate.
     pub compound: bool,
     /// Tax total (not including shipping taxes).
-    pub tax_total: String,
+    pub tax_total: Money,
     /// Shipping tax total.
-    pub shipping_tax_total: String,
+    pub shipping_tax_total: Money,
     /// Meta data.
     pub meta_data: Vec<MetaData>,
 }
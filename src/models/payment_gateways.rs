@@ -1,7 +1,10 @@
+use std::collections::BTreeMap;
+
 use crate::controllers::Entity;
 use serde::{Deserialize, Serialize};
 
-use crate::controllers::payment_gateways::PaymentGatewayUpdate;
+use super::{empty_array_as_default, empty_as_none, string_or_number};
+use crate::controllers::payment_gateways::{PaymentGatewayUpdate, PaymentGatewayUpdateBuilder};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaymentGateway {
     /// Payment gateway ID.
@@ -11,6 +14,7 @@ pub struct PaymentGateway {
     /// Payment gateway description on checkout.
     pub description: String,
     /// Payment gateway sort order.
+    #[serde(deserialize_with = "string_or_number")]
     pub order: String,
     /// Payment gateway enabled status.
     pub enabled: bool,
@@ -21,9 +25,11 @@ pub struct PaymentGateway {
     /// Supported features for this payment gateway.
     pub method_supports: Vec<String>,
     /// Payment gateway settings.
+    #[serde(default, deserialize_with = "empty_array_as_default")]
     pub settings: PaymentGatewaySettings,
 }
 impl Entity for PaymentGateway {
+    type Id = String;
     fn endpoint() -> String {
         String::from("payment_gateways/")
     }
@@ -34,40 +40,51 @@ impl Entity for PaymentGateway {
     }
 }
 impl PaymentGateway {
+    /// Update builder, e.g. `client.update::<PaymentGateway>("bacs", PaymentGateway::update().title("Bank transfer").build())`
+    pub fn update() -> PaymentGatewayUpdateBuilder {
+        PaymentGatewayUpdateBuilder::default()
+    }
     pub fn turn_on() -> PaymentGatewayUpdate {
-        PaymentGatewayUpdate { enabled: true }
+        Self::update().enabled(true).build()
     }
     pub fn turn_off() -> PaymentGatewayUpdate {
-        PaymentGatewayUpdate { enabled: false }
+        Self::update().enabled(false).build()
     }
 }
+/// Payment gateway settings keyed by setting ID.
+pub type PaymentGatewaySettings = BTreeMap<String, PaymentGatewaySetting>;
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PaymentGatewaySettings {
-    pub title: TitleInstructions,
-    pub instructions: TitleInstructions,
-}
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TitleInstructions {
+pub struct PaymentGatewaySetting {
     /// A unique identifier for the setting.
     pub id: String,
     /// A human readable label for the setting used in interfaces.
     pub label: String,
     /// A human readable description for the setting used in interfaces.
+    #[serde(default)]
     pub description: String,
     /// Type of setting. Options: text, email, number, color, password, textarea, select, multiselect, radio, image_width and checkbox.
     #[serde(rename = "type")]
     pub setting_type: SettingType,
     /// Setting value.
-    pub value: String,
+    pub value: serde_json::Value,
     /// Default value for the setting.
-    pub default: String,
+    #[serde(default)]
+    pub default: serde_json::Value,
     /// Additional help text shown to the user about the setting.
-    pub tip: String,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub tip: Option<String>,
     /// Placeholder text to be displayed in text inputs.
+    #[serde(default)]
     pub placeholder: String,
+    /// Array of options (key value pairs) for inputs such as select, multiselect, and radio buttons.
+    #[serde(default, deserialize_with = "empty_array_as_default")]
+    pub options: Option<BTreeMap<String, String>>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+/// Type of a setting.
+///
+/// Gateways and extensions may define their own types, those are kept in [`SettingType::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum SettingType {
     Text,
     Email,
@@ -81,4 +98,23 @@ pub enum SettingType {
     ImageWidth,
     Checkbox,
     SafeText,
+    ThumbnailCropping,
+    Title,
+    Other(String),
 }
+string_enum!(SettingType {
+    Text => "text",
+    Email => "email",
+    Number => "number",
+    Color => "color",
+    Password => "password",
+    Textarea => "textarea",
+    Select => "select",
+    Multiselect => "multiselect",
+    Radio => "radio",
+    ImageWidth => "image_width",
+    Checkbox => "checkbox",
+    SafeText => "safe_text",
+    ThumbnailCropping => "thumbnail_cropping",
+    Title => "title",
+});
@@ -20,6 +20,7 @@ pub struct AttributeTerm {
     pub count: i32,
 }
 impl Entity for AttributeTerm {
+    type Id = i32;
     fn endpoint() -> String {
         String::new()
     }
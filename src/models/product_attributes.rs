@@ -24,6 +24,7 @@ pub struct Attribute {
     pub has_archives: bool,
 }
 impl Entity for Attribute {
+    type Id = i32;
     fn endpoint() -> String {
         String::from("products/attributes/")
     }
@@ -45,18 +46,29 @@ impl Attribute {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "lowercase")]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(from = "String", into = "String")]
 pub enum AttributeType {
     #[default]
     Select,
+    Other(String),
 }
+string_enum!(AttributeType {
+    Select => "select",
+});
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[serde(from = "String", into = "String")]
 pub enum AttributeSortOrder {
     #[default]
     MenuOrder,
     Name,
     NameNum,
     Id,
+    Other(String),
 }
+string_enum!(AttributeSortOrder {
+    MenuOrder => "menu_order",
+    Name => "name",
+    NameNum => "name_num",
+    Id => "id",
+});
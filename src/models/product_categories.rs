@@ -35,6 +35,7 @@ impl Category {
 }
 
 impl Entity for Category {
+    type Id = i32;
     fn endpoint() -> String {
         String::from("products/categories/")
     }
@@ -44,11 +45,18 @@ impl Entity for Category {
     }
 }
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-#[serde(rename_all = "lowercase")]
+#[serde(from = "String", into = "String")]
 pub enum DisplayOption {
     #[default]
     Default,
     Products,
     Subcategories,
     Both,
+    Other(String),
 }
+string_enum!(DisplayOption {
+    Default => "default",
+    Products => "products",
+    Subcategories => "subcategories",
+    Both => "both",
+});
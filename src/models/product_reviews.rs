@@ -1,5 +1,5 @@
 use crate::controllers::Entity;
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::controllers::product_reviews::{
@@ -29,6 +29,7 @@ pub struct ProductReview {
     pub verified: bool,
 }
 impl Entity for ProductReview {
+    type Id = i32;
     fn endpoint() -> String {
         String::from("products/reviews/")
     }
@@ -45,9 +46,13 @@ impl ProductReview {
     pub fn update() -> ProductReviewUpdateBuilder {
         ProductReviewUpdateBuilder::default()
     }
+    /// The date the review was created, from `date_created_gmt`
+    pub fn date_created_utc(&self) -> DateTime<Utc> {
+        self.date_created_gmt.and_utc()
+    }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "lowercase")]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(from = "String", into = "String")]
 pub enum ReviewStatus {
     #[default]
     Approved,
@@ -56,4 +61,13 @@ pub enum ReviewStatus {
     Unspam,
     Trash,
     Untrash,
+    Other(String),
 }
+string_enum!(ReviewStatus {
+    Approved => "approved",
+    Hold => "hold",
+    Spam => "spam",
+    Unspam => "unspam",
+    Trash => "trash",
+    Untrash => "untrash",
+});
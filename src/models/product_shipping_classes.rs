@@ -18,6 +18,7 @@ pub struct ShippingClass {
     pub count: i32,
 }
 impl Entity for ShippingClass {
+    type Id = i32;
     fn endpoint() -> String {
         String::from("products/shipping_classes/")
     }
@@ -16,6 +16,7 @@ pub struct ProductTag {
     pub count: i32,
 }
 impl Entity for ProductTag {
+    type Id = i32;
     fn endpoint() -> String {
         String::from("products/tags/")
     }
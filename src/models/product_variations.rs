@@ -1,6 +1,7 @@
 use crate::controllers::product_variations::ProductVariationModifyBuilder;
 
 use super::{
+    empty_as_none, extra_fields, lenient_string,
     products::{
         BackordersStatus, Dimensions, Download, ProductDefaultAttribute, ProductImage,
         ProductStatus, StockStatus, TaxStatus,
@@ -8,40 +9,56 @@ use super::{
     MetaData,
 };
 use crate::controllers::Entity;
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::money::Money;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProductVariation {
     /// Unique identifier for the resource.
     pub id: i32,
     /// The date the variation was created, in the site's timezone.
-    pub date_created: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_created: Option<NaiveDateTime>,
     /// The date the variation was created, as GMT.
-    pub date_created_gmt: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_created_gmt: Option<NaiveDateTime>,
     /// The date the variation was last modified, in the site's timezone.
-    pub date_modified: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_modified: Option<NaiveDateTime>,
     /// The date the variation was last modified, as GMT.
-    pub date_modified_gmt: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_modified_gmt: Option<NaiveDateTime>,
     /// Variation description.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub description: String,
     /// Variation URL.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub permalink: String,
     /// Unique identifier.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub sku: String,
     /// Current variation price.
-    pub price: String,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub price: Option<Money>,
     /// Variation regular price.
-    pub regular_price: String,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub regular_price: Option<Money>,
     /// Variation sale price.
-    pub sale_price: String,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub sale_price: Option<Money>,
     /// Start date of sale price, in the site's timezone.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_on_sale_from: Option<NaiveDateTime>,
     /// Start date of sale price, as GMT.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_on_sale_from_gmt: Option<NaiveDateTime>,
     /// End date of sale price, in the site's timezone.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_on_sale_to: Option<NaiveDateTime>,
     /// End date of sale price, as GMT.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_on_sale_to_gmt: Option<NaiveDateTime>,
     /// Shows if the variation is on sale. READ-ONLY
     pub on_sale: bool,
@@ -63,10 +80,12 @@ pub struct ProductVariation {
     /// Tax status. Options: taxable, shipping and none. Default is taxable.
     pub tax_status: TaxStatus,
     /// Tax class.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub tax_class: String,
     /// Stock management at variation level. Default is false.
     pub manage_stock: ManageStock,
     /// Stock quantity.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub stock_quantity: Option<i32>,
     /// Controls the stock status of the product. Options: instock, outofstock, onbackorder. Default is instock.
     pub stock_status: StockStatus,
@@ -77,14 +96,17 @@ pub struct ProductVariation {
     /// Shows if the variation is on backordered.
     pub backordered: bool,
     /// Variation weight.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub weight: String,
     /// Variation dimensions. See Product variation - Dimensions properties
     pub dimensions: Dimensions,
     /// Shipping class slug.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub shipping_class: String,
     /// Shipping class ID.
     pub shipping_class_id: i32,
     /// Variation image data.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub image: Option<ProductImage>,
     /// List of attributes.
     pub attributes: Vec<ProductDefaultAttribute>,
@@ -92,9 +114,13 @@ pub struct ProductVariation {
     pub menu_order: i32,
     /// Meta data.
     pub meta_data: Vec<MetaData>,
+    /// Fields not modelled by this crate, e.g. added by plugins.
+    #[serde(flatten, deserialize_with = "extra_fields")]
+    pub extra: serde_json::Value,
 }
 
 impl Entity for ProductVariation {
+    type Id = i32;
     fn endpoint() -> String {
         String::new()
     }
@@ -107,6 +133,22 @@ impl ProductVariation {
     pub fn builder() -> ProductVariationModifyBuilder {
         ProductVariationModifyBuilder::default()
     }
+    /// The date the variation was created, from `date_created_gmt`
+    pub fn date_created_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_created_gmt.map(|date| date.and_utc())
+    }
+    /// The date the variation was last modified, from `date_modified_gmt`
+    pub fn date_modified_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_modified_gmt.map(|date| date.and_utc())
+    }
+    /// Start date of sale price, from `date_on_sale_from_gmt`
+    pub fn date_on_sale_from_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_on_sale_from_gmt.map(|date| date.and_utc())
+    }
+    /// End date of sale price, from `date_on_sale_to_gmt`
+    pub fn date_on_sale_to_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_on_sale_to_gmt.map(|date| date.and_utc())
+    }
 }
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -1,29 +1,38 @@
 use crate::controllers::{
-    products::{ProductModify, ProductModifyBuilder},
+    products::{ProductModify, ProductModifyBuilder, ProductQuery},
     Entity,
 };
 
-use super::MetaData;
-use chrono::NaiveDateTime;
+use super::{empty_as_none, extra_fields, lenient_string, MetaData};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
+
+use super::money::Money;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Product {
     /// Unique identifier for the resource.
     pub id: i32,
     /// Product name.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub name: String,
     /// Product slug.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub slug: String,
     /// Product URL.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub permalink: String,
     /// The date the product was created, in the site's timezone.
-    pub date_created: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_created: Option<NaiveDateTime>,
     /// The date the product was created, as GMT.
-    pub date_created_gmt: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_created_gmt: Option<NaiveDateTime>,
     /// The date the product was last modified, in the site's timezone.
-    pub date_modified: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_modified: Option<NaiveDateTime>,
     /// The date the product was last modified, as GMT.
-    pub date_modified_gmt: NaiveDateTime,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_modified_gmt: Option<NaiveDateTime>,
     /// Product type, Options: simple, grouped, external and variable. Default is simple.
     #[serde(rename = "type")]
     pub product_type: ProductType,
@@ -34,26 +43,37 @@ pub struct Product {
     /// Catalog visibility. Options: visible, catalog, search and hidden. Default is visible.
     pub catalog_visibility: CatalogVisibility,
     /// Product description.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub description: String,
     /// Product short description.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub short_description: String,
     /// Unique identifier.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub sku: String,
     /// Current product price.
-    pub price: String,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub price: Option<Money>,
     /// Product regular price.
-    pub regular_price: String,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub regular_price: Option<Money>,
     /// Product sale price.
-    pub sale_price: String,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub sale_price: Option<Money>,
     /// Start date of sale price, in the site's timezone.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_on_sale_from: Option<NaiveDateTime>,
     /// Start date of sale price, as GMT.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_on_sale_from_gmt: Option<NaiveDateTime>,
     /// End date of sale price, in the site's timezone.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_on_sale_to: Option<NaiveDateTime>,
     /// End date of sale price, as GMT.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub date_on_sale_to_gmt: Option<NaiveDateTime>,
     /// Price formatted in HTML.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub price_html: String,
     /// Shows if the product is on sale.
     pub on_sale: bool,
@@ -73,16 +93,20 @@ pub struct Product {
     /// Number of days until access to downloadable files expires. Default is -1.
     pub download_expiry: i32,
     /// Product external URL. Only for external products.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub external_url: String,
     /// Product external button text. Only for external products.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub button_text: String,
     /// Tax status. Options: taxable, shipping and none. Default is taxable.
     pub tax_status: TaxStatus,
     /// Tax class.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub tax_class: String,
     /// Stock management at product level. Default is false.
     pub manage_stock: bool,
     /// Stock quantity.
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub stock_quantity: Option<i32>,
     /// Controls the stock status of the product. Options: instock, outofstock, onbackorder. Default is instock.
     pub stock_status: StockStatus,
@@ -95,6 +119,7 @@ pub struct Product {
     /// Allow one item to be bought in a single order. Default is false.
     pub sold_individually: bool,
     /// Product weight.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub weight: String,
     /// Product dimensions.
     pub dimensions: Dimensions,
@@ -103,12 +128,14 @@ pub struct Product {
     /// Shows whether or not the product shipping is taxable.READ-ONLY
     pub shipping_taxable: bool,
     /// Shipping class slug.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub shipping_class: String,
     /// Shipping class ID.
     pub shipping_class_id: i32,
     /// Allow reviews. Default is true.
     pub reviews_allowed: bool,
     /// Reviews average rating.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub average_rating: String,
     /// Amount of reviews that the product have.
     pub rating_count: i32,
@@ -121,6 +148,7 @@ pub struct Product {
     /// Product parent ID.
     pub parent_id: i32,
     /// Optional note to send the customer after purchase.
+    #[serde(default, deserialize_with = "lenient_string")]
     pub purchase_note: String,
     /// List of categories.
     pub categories: Vec<ProductCategory>,
@@ -140,8 +168,12 @@ pub struct Product {
     pub menu_order: i32,
     /// Meta data.
     pub meta_data: Vec<MetaData>,
+    /// Fields not modelled by this crate, e.g. added by plugins.
+    #[serde(flatten, deserialize_with = "extra_fields")]
+    pub extra: serde_json::Value,
 }
 impl Entity for Product {
+    type Id = i32;
     fn endpoint() -> String {
         String::from("products/")
     }
@@ -154,58 +186,116 @@ impl Product {
     pub fn builder() -> ProductModifyBuilder {
         ProductModify::builder()
     }
+    pub fn query() -> ProductQuery {
+        ProductQuery::default()
+    }
+    /// The date the product was created, from `date_created_gmt`
+    pub fn date_created_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_created_gmt.map(|date| date.and_utc())
+    }
+    /// The date the product was last modified, from `date_modified_gmt`
+    pub fn date_modified_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_modified_gmt.map(|date| date.and_utc())
+    }
+    /// Start date of sale price, from `date_on_sale_from_gmt`
+    pub fn date_on_sale_from_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_on_sale_from_gmt.map(|date| date.and_utc())
+    }
+    /// End date of sale price, from `date_on_sale_to_gmt`
+    pub fn date_on_sale_to_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_on_sale_to_gmt.map(|date| date.and_utc())
+    }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "lowercase")]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(from = "String", into = "String")]
 pub enum ProductType {
     #[default]
     Simple,
     Grouped,
     External,
     Variable,
+    Other(String),
 }
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "lowercase")]
+string_enum!(ProductType {
+    Simple => "simple",
+    Grouped => "grouped",
+    External => "external",
+    Variable => "variable",
+});
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(from = "String", into = "String")]
 pub enum ProductStatus {
     Draft,
     Pending,
     Private,
     #[default]
     Publish,
+    Other(String),
 }
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "lowercase")]
+string_enum!(ProductStatus {
+    Draft => "draft",
+    Pending => "pending",
+    Private => "private",
+    Publish => "publish",
+});
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(from = "String", into = "String")]
 pub enum CatalogVisibility {
     #[default]
     Visible,
     Catalog,
     Search,
     Hidden,
+    Other(String),
 }
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "lowercase")]
+string_enum!(CatalogVisibility {
+    Visible => "visible",
+    Catalog => "catalog",
+    Search => "search",
+    Hidden => "hidden",
+});
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(from = "String", into = "String")]
 pub enum TaxStatus {
     #[default]
     Taxable,
     Shipping,
     None,
+    Other(String),
 }
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "lowercase")]
+string_enum!(TaxStatus {
+    Taxable => "taxable",
+    Shipping => "shipping",
+    None => "none",
+});
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(from = "String", into = "String")]
 pub enum StockStatus {
     #[default]
     Instock,
     Outofstock,
     Onbackorder,
+    Other(String),
 }
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "lowercase")]
+string_enum!(StockStatus {
+    Instock => "instock",
+    Outofstock => "outofstock",
+    Onbackorder => "onbackorder",
+});
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(from = "String", into = "String")]
 pub enum BackordersStatus {
     #[default]
     No,
     Notify,
     Yes,
+    Other(String),
 }
+string_enum!(BackordersStatus {
+    No => "no",
+    Notify => "notify",
+    Yes => "yes",
+});
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Download {
     /// File ID.
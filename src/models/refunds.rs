@@ -1,10 +1,12 @@
 use crate::controllers::refunds::{NoAmount, NoItems, RefundCreate, RefundCreateBuilder};
 
-use super::MetaData;
+use super::{empty_as_none, MetaData};
 use crate::controllers::Entity;
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::money::Money;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Refund {
     /// Unique identifier for the resource.
@@ -14,7 +16,8 @@ pub struct Refund {
     /// The date the order refund was created, as GMT.
     pub date_created_gmt: NaiveDateTime,
     /// Total refund amount. Optional. If this parameter is provided, it will take precedence over line item totals, even when total of line items does not matches with this amount.
-    pub amount: Option<String>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub amount: Option<Money>,
     /// Reason for refund.
     pub reason: String,
     /// User ID of user who created the refund.    
@@ -31,6 +34,7 @@ pub struct Refund {
     // pub api_restock: bool,
 }
 impl Entity for Refund {
+    type Id = i32;
     fn endpoint() -> String {
         String::new()
     }
@@ -54,13 +58,13 @@ pub struct OrderRefundLineItem {
     /// Tax class of product.
     pub tax_class: i32,
     /// Line subtotal (before discounts).
-    pub subtotal: String,
+    pub subtotal: Money,
     /// Line subtotal tax (before discounts).
-    pub subtotal_tax: String,
+    pub subtotal_tax: Money,
     /// Line total (after discounts).    
-    pub total: String,
+    pub total: Money,
     /// Line total tax (after discounts).
-    pub total_tax: String,
+    pub total_tax: Money,
     /// Line taxes.    
     pub taxes: Vec<OrderRefundLineItemTaxesProperties>,
     /// Meta data.
@@ -68,7 +72,7 @@ pub struct OrderRefundLineItem {
     /// Product SKU.    
     pub sku: String,
     /// Product price.    
-    pub price: String,
+    pub price: Money,
     // The amount to refund for this line item, excluding taxes.
     // pub refund_total: f64,
 }
@@ -77,9 +81,9 @@ pub struct OrderRefundLineItemTaxesProperties {
     /// Tax rate ID.
     pub id: i32,
     /// Tax total.
-    pub total: String,
+    pub total: Money,
     /// Tax subtotal.
-    pub subtotal: String,
+    pub subtotal: Money,
     // The amount to refund for this tax.
     // pub refund_total: f64,
 }
@@ -87,4 +91,8 @@ impl Refund {
     pub fn create() -> RefundCreateBuilder<NoAmount, NoItems> {
         RefundCreate::builder()
     }
+    /// The date the refund was created, from `date_created_gmt`
+    pub fn date_created_utc(&self) -> DateTime<Utc> {
+        self.date_created_gmt.and_utc()
+    }
 }
@@ -2,12 +2,15 @@ use crate::controllers::Entity;
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+use super::money::Money;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Report {
     pub slug: String,
     pub description: String,
 }
 impl Entity for Report {
+    type Id = String;
     fn endpoint() -> String {
         String::from("reports/")
     }
@@ -21,23 +24,23 @@ impl Entity for Report {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SaleReport {
     /// Gross sales in the period.
-    pub total_sales: String,
+    pub total_sales: Money,
     /// Net sales in the period.
-    pub net_sales: String,
+    pub net_sales: Money,
     /// Average net daily sales.
-    pub average_sales: String,
+    pub average_sales: Money,
     /// Total of orders placed.
     pub total_orders: i32,
     /// Total of items purchased.
     pub total_items: i32,
     /// Total charged for taxes.
-    pub total_tax: String,
+    pub total_tax: Money,
     /// Total charged for shipping.
-    pub total_shipping: String,
+    pub total_shipping: Money,
     /// Total of refunded orders.
     pub total_refunds: i32,
     /// Total of coupons used.
-    pub total_discount: String,
+    pub total_discount: Money,
     /// Group type.
     pub totals_grouped_by: String,
     /// Totals.
@@ -45,6 +48,7 @@ pub struct SaleReport {
     pub total_customers: i32,
 }
 impl Entity for SaleReport {
+    type Id = String;
     fn endpoint() -> String {
         String::from("reports/sales/")
     }
@@ -56,12 +60,12 @@ impl Entity for SaleReport {
 }
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Total {
-    pub sales: String,
+    pub sales: Money,
     pub orders: i32,
     pub items: i32,
-    pub tax: String,
-    pub shipping: String,
-    pub discount: String,
+    pub tax: Money,
+    pub shipping: Money,
+    pub discount: Money,
     pub customers: i32,
 }
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -75,6 +79,7 @@ pub struct TopSellersReport {
 }
 
 impl Entity for TopSellersReport {
+    type Id = String;
     fn endpoint() -> String {
         String::from("reports/top_sellers/")
     }
@@ -92,6 +97,7 @@ pub struct ReportOrdersTotals {
     pub total: i32,
 }
 impl Entity for ReportOrdersTotals {
+    type Id = String;
     fn endpoint() -> String {
         String::from("reports/orders/totals/")
     }
@@ -1,6 +1,10 @@
+use std::collections::BTreeMap;
+
 use crate::controllers::Entity;
 use serde::{Deserialize, Serialize};
 
+use super::{empty_array_as_default, payment_gateways::SettingType};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SettingGroup {
     /// A unique identifier that can be used to link settings together.
@@ -15,6 +19,7 @@ pub struct SettingGroup {
     pub sub_groups: Vec<String>,
 }
 impl Entity for SettingGroup {
+    type Id = String;
     fn endpoint() -> String {
         String::from("settings/")
     }
@@ -24,3 +29,31 @@ impl Entity for SettingGroup {
         String::new()
     }
 }
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingOption {
+    /// A unique identifier for the setting.
+    pub id: String,
+    /// An identifier for the group this setting belongs to.
+    #[serde(default)]
+    pub group_id: String,
+    /// A human readable label for the setting used in interfaces.
+    pub label: String,
+    /// A human readable description for the setting used in interfaces.
+    #[serde(default)]
+    pub description: String,
+    /// Setting value.
+    pub value: serde_json::Value,
+    /// Default value for the setting.
+    #[serde(default)]
+    pub default: serde_json::Value,
+    /// Additional help text shown to the user about the setting.
+    pub tip: Option<String>,
+    /// Placeholder text to be displayed in text inputs.
+    pub placeholder: Option<String>,
+    /// Type of setting. Options: text, email, number, color, password, textarea, select, multiselect, radio, image_width and checkbox.
+    #[serde(rename = "type")]
+    pub setting_type: SettingType,
+    /// Array of options (key value pairs) for inputs such as select, multiselect, and radio buttons.
+    #[serde(default, deserialize_with = "empty_array_as_default")]
+    pub options: Option<BTreeMap<String, String>>,
+}
@@ -11,6 +11,7 @@ pub struct ShippingMethod {
     pub description: String,
 }
 impl Entity for ShippingMethod {
+    type Id = String;
     fn endpoint() -> String {
         String::from("shipping_methods/")
     }
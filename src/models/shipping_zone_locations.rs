@@ -1,15 +1,16 @@
 use crate::controllers::Entity;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ShippingZoneLocation {
     /// Shipping zone location code.
     pub code: String,
     /// Shipping zone location type. Options: postcode, state, country and continent. Default is country.
-    #[serde(rename = "type")]
+    #[serde(rename = "type", default)]
     pub location_type: LocationType,
 }
 impl Entity for ShippingZoneLocation {
+    type Id = String;
     fn endpoint() -> String {
         String::new()
     }
@@ -18,11 +19,43 @@ impl Entity for ShippingZoneLocation {
         format!("shipping/zones/{parent_id}/locations/")
     }
 }
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+impl ShippingZoneLocation {
+    /// Location matching a postcode, wildcards (`902*`) and ranges (`90210...90299`) are allowed.
+    pub fn postcode(code: impl Into<String>) -> Self {
+        Self::new(code, LocationType::Postcode)
+    }
+    /// Location matching a state in `country:state` form, e.g. `US:CA`.
+    pub fn state(code: impl Into<String>) -> Self {
+        Self::new(code, LocationType::State)
+    }
+    /// Location matching an ISO3166 alpha-2 country code.
+    pub fn country(code: impl Into<String>) -> Self {
+        Self::new(code, LocationType::Country)
+    }
+    /// Location matching a continent code, e.g. `EU`.
+    pub fn continent(code: impl Into<String>) -> Self {
+        Self::new(code, LocationType::Continent)
+    }
+    fn new(code: impl Into<String>, location_type: LocationType) -> Self {
+        Self {
+            code: code.into(),
+            location_type,
+        }
+    }
+}
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[serde(from = "String", into = "String")]
 pub enum LocationType {
     Postcode,
     State,
+    #[default]
     Country,
     Continent,
+    Other(String),
 }
+string_enum!(LocationType {
+    Postcode => "postcode",
+    State => "state",
+    Country => "country",
+    Continent => "continent",
+});
@@ -1,6 +1,13 @@
+use std::collections::BTreeMap;
+
 use crate::controllers::Entity;
 use serde::{Deserialize, Serialize};
 
+use super::{empty_array_as_default, payment_gateways::SettingType};
+use crate::controllers::shipping_zone_methods::{
+    ShippingZoneMethodCreate, ShippingZoneMethodUpdate,
+};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShippingZoneMethod {
     pub id: i64,
@@ -18,10 +25,12 @@ pub struct ShippingZoneMethod {
     pub method_title: String,
     /// Shipping method description.
     pub method_description: String,
-    /// Shipping method settings.
+    /// Shipping method settings keyed by setting ID, e.g. `title` and `cost` for flat rate.
+    #[serde(default, deserialize_with = "empty_array_as_default")]
     pub settings: ShippingSettings,
 }
 impl Entity for ShippingZoneMethod {
+    type Id = i32;
     fn endpoint() -> String {
         String::new()
     }
@@ -30,11 +39,21 @@ impl Entity for ShippingZoneMethod {
         format!("shipping/zones/{parent_id}/methods/")
     }
 }
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ShippingSettings {
-    pub title: ShippingMethodSettings,
-    pub requires: Option<ShippingMethodSettings>,
+impl ShippingZoneMethod {
+    /// Add a shipping method to a zone, e.g. `ShippingZoneMethod::include("flat_rate").setting("cost", "5")`
+    pub fn include(method_id: impl Into<String>) -> ShippingZoneMethodCreate {
+        ShippingZoneMethodCreate::new(method_id)
+    }
+    pub fn update() -> ShippingZoneMethodUpdate {
+        ShippingZoneMethodUpdate::default()
+    }
+    /// Current value of a setting
+    pub fn setting(&self, id: &str) -> Option<&str> {
+        self.settings.get(id).map(|s| s.value.as_str())
+    }
 }
+/// Shipping method settings keyed by setting ID.
+pub type ShippingSettings = BTreeMap<String, ShippingMethodSettings>;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShippingMethodSettings {
     /// A unique identifier for the setting.
@@ -42,33 +61,23 @@ pub struct ShippingMethodSettings {
     /// A human readable label for the setting used in interfaces.
     pub label: String,
     /// A human readable description for the setting used in interfaces.
+    #[serde(default)]
     pub description: String,
     /// Type of setting. Options: text, email, number, color, password, textarea, select, multiselect, radio, image_width and checkbox.
     #[serde(rename = "type")]
-    pub settings_type: SettingsType,
+    pub settings_type: SettingType,
     /// Setting value.
     pub value: String,
     /// Default value for the setting.
-    #[serde(rename = "default")]
+    #[serde(rename = "default", default)]
     pub default_value: String,
     /// Additional help text shown to the user about the setting.
+    #[serde(default)]
     pub tip: String,
     /// Placeholder text to be displayed in text inputs.
+    #[serde(default)]
     pub placeholder: String,
     pub options: Option<serde_json::Value>,
 }
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum SettingsType {
-    Text,
-    Email,
-    Number,
-    Color,
-    Password,
-    Textarea,
-    Select,
-    Multiselect,
-    Radio,
-    ImageWidth,
-    Checkbox,
-}
+/// Type of a shipping method setting, same as [`SettingType`].
+pub type SettingsType = SettingType;
@@ -12,6 +12,7 @@ pub struct ShippingZone {
     pub order: i32,
 }
 impl Entity for ShippingZone {
+    type Id = i32;
     fn endpoint() -> String {
         String::from("shipping/zones/")
     }
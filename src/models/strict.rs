@@ -0,0 +1,35 @@
+use std::cell::Cell;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+thread_local! {
+    static STRICT: Cell<bool> = const { Cell::new(false) };
+}
+/// Whether the models are being read in strict mode on this thread
+pub(crate) fn is_strict() -> bool {
+    STRICT.with(Cell::get)
+}
+/// Run `f` with strict mode set to `strict`, restoring the previous mode afterwards even if `f` panics
+pub(crate) fn with_strict<R>(strict: bool, f: impl FnOnce() -> R) -> R {
+    struct Restore(bool);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            STRICT.with(|cell| cell.set(self.0));
+        }
+    }
+    let _restore = Restore(STRICT.with(|cell| cell.replace(strict)));
+    f()
+}
+/// Deserialize a model from a JSON string, rejecting the quirks and unknown fields that are otherwise tolerated
+pub fn from_str<T: DeserializeOwned>(s: &str) -> serde_json::Result<T> {
+    with_strict(true, || serde_json::from_str(s))
+}
+/// Deserialize a model from JSON bytes, rejecting the quirks and unknown fields that are otherwise tolerated
+pub fn from_slice<T: DeserializeOwned>(v: &[u8]) -> serde_json::Result<T> {
+    with_strict(true, || serde_json::from_slice(v))
+}
+/// Deserialize a model from a JSON value, rejecting the quirks and unknown fields that are otherwise tolerated
+pub fn from_value<T: DeserializeOwned>(value: Value) -> serde_json::Result<T> {
+    with_strict(true, || serde_json::from_value(value))
+}
@@ -10,6 +10,7 @@ pub struct TaxClass {
     pub name: String,
 }
 impl Entity for TaxClass {
+    type Id = String;
     fn endpoint() -> String {
         String::from("taxes/classes/")
     }
@@ -34,6 +34,7 @@ pub struct TaxRate {
     pub class: String,
 }
 impl Entity for TaxRate {
+    type Id = i32;
     fn endpoint() -> String {
         String::from("taxes/")
     }
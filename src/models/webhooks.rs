@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
 use crate::controllers::Entity;
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::controllers::webhooks::{WebhookCreateBuilder, WebhookUpdateBuilder};
@@ -14,7 +14,7 @@ pub struct Webhook {
     /// Webhook status. Options: active, paused and disabled. Default is active.
     pub status: WebhookStatus,
     /// Webhook topic.
-    pub topic: String,
+    pub topic: Topic,
     /// Webhook resource.
     pub resource: Resource,
     /// Webhook event.
@@ -35,6 +35,7 @@ pub struct Webhook {
     pub date_modified_gmt: Option<NaiveDateTime>,
 }
 impl Entity for Webhook {
+    type Id = i32;
     fn endpoint() -> String {
         String::from("webhooks/")
     }
@@ -57,47 +58,123 @@ impl Webhook {
     pub fn update() -> WebhookUpdateBuilder {
         WebhookUpdateBuilder::default()
     }
+    /// The date the webhook was created, from `date_created_gmt`
+    pub fn date_created_utc(&self) -> DateTime<Utc> {
+        self.date_created_gmt.and_utc()
+    }
+    /// The date the webhook was last modified, from `date_modified_gmt`
+    pub fn date_modified_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_modified_gmt.map(|date| date.and_utc())
+    }
 }
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+/// Webhook status, statuses added by future WooCommerce versions are kept in [`WebhookStatus::Other`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum WebhookStatus {
     Active,
     Paused,
     Disabled,
+    Other(String),
 }
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+string_enum!(WebhookStatus {
+    Active => "active",
+    Paused => "paused",
+    Disabled => "disabled",
+});
+/// Webhook resource, resources defined by plugins are kept in [`Resource::Other`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum Resource {
     Coupon,
     Customer,
     Order,
     Product,
+    /// Resource of `action.*` topics, their event is the action name.
+    Action,
+    Other(String),
 }
-impl Display for Resource {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Resource::Coupon => write!(f, "coupon"),
-            Resource::Customer => write!(f, "customer"),
-            Resource::Order => write!(f, "order"),
-            Resource::Product => write!(f, "product"),
-        }
-    }
-}
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+string_enum!(Resource {
+    Coupon => "coupon",
+    Customer => "customer",
+    Order => "order",
+    Product => "product",
+    Action => "action",
+});
+/// Webhook event, action names and events defined by plugins are kept in [`Event::Other`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum Event {
     Created,
     Updated,
     Deleted,
     Restored,
+    Other(String),
+}
+string_enum!(Event {
+    Created => "created",
+    Updated => "updated",
+    Deleted => "deleted",
+    Restored => "restored",
+});
+/// Webhook topic, e.g. `order.created` or `action.woocommerce_add_to_cart`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Topic {
+    /// Event of a resource, e.g. order.created.
+    Resource(Resource, Event),
+    /// Custom topic fired by a WordPress action, holding the action name.
+    Action(String),
+    /// Topic that is not in `<resource>.<event>` form.
+    Other(String),
+}
+impl Topic {
+    /// Topic of an event of a resource, `action` resources become [`Topic::Action`]
+    pub fn new(resource: Resource, event: Event) -> Self {
+        match resource {
+            Resource::Action => Topic::Action(event.to_string()),
+            resource => Topic::Resource(resource, event),
+        }
+    }
+    /// Custom topic fired by a WordPress action
+    pub fn action(name: impl Into<String>) -> Self {
+        Topic::Action(name.into())
+    }
+    /// Resource and event of the topic, None for [`Topic::Other`]
+    pub fn parts(&self) -> Option<(Resource, Event)> {
+        match self {
+            Topic::Resource(resource, event) => Some((resource.clone(), event.clone())),
+            Topic::Action(name) => Some((Resource::Action, Event::Other(name.clone()))),
+            Topic::Other(_) => None,
+        }
+    }
+}
+impl From<String> for Topic {
+    fn from(value: String) -> Self {
+        match value.split_once('.') {
+            Some((resource, event)) if !resource.is_empty() && !event.is_empty() => Topic::new(
+                Resource::from(resource.to_owned()),
+                Event::from(event.to_owned()),
+            ),
+            _ => Topic::Other(value),
+        }
+    }
+}
+impl From<&str> for Topic {
+    fn from(value: &str) -> Self {
+        Topic::from(value.to_owned())
+    }
+}
+impl From<Topic> for String {
+    fn from(value: Topic) -> Self {
+        value.to_string()
+    }
 }
-impl Display for Event {
+impl Display for Topic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Event::Created => write!(f, "created"),
-            Event::Updated => write!(f, "updated"),
-            Event::Deleted => write!(f, "deleted"),
-            Event::Restored => write!(f, "restored"),
+            Topic::Resource(resource, event) => write!(f, "{resource}.{event}"),
+            Topic::Action(name) => write!(f, "action.{name}"),
+            Topic::Other(other) => write!(f, "{other}"),
         }
     }
 }
@@ -0,0 +1,895 @@
+use std::collections::{BTreeMap, HashMap};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::header::{AUTHORIZATION, CONTENT_TYPE, ETAG, IF_NONE_MATCH, LINK, RETRY_AFTER};
+use axum::http::{HeaderMap, HeaderValue, Method, StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+use axum::Router;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use tokio::task::JoinHandle;
+
+use crate::{ApiClient, ApiClientBuilder, Entity, Error, Result};
+
+/// Namespaces served by the mock, the path below them selects the collection
+const NAMESPACES: [&str; 3] = [
+    "/wp-json/wc/v3/",
+    "/wp-json/wc/v2/",
+    "/wp-json/wc-analytics/",
+];
+const DEFAULT_PER_PAGE: usize = 10;
+/// Credentials set by [`MockServer::client_builder`]
+const CONSUMER_KEY: &str = "ck_mock";
+const CONSUMER_SECRET: &str = "cs_mock";
+/// `rawurlencode` of PHP, which WooCommerce uses for OAuth 1.0a signatures
+const RAWURLENCODE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Local WooCommerce REST server keeping entities in memory, for tests that must not reach a real store.
+///
+/// Collections are addressed by their endpoint, e.g. `products` or `products/7/variations`, and support
+/// listing with `X-WP-Total`/`X-WP-TotalPages`/`Link` pagination headers, retrieve, create, update, delete
+/// and batch requests. GET responses carry an `ETag` and honor `If-None-Match`. Requests must carry the credentials
+/// of [`MockServer::client_builder`], as Basic auth, query string or an OAuth 1.0a signature checked the way
+/// WooCommerce does, otherwise they are answered with `401 Unauthorized`.
+///
+/// Created entities are the request body laid over a template of the resource, fields the template does not
+/// have are ignored like WooCommerce does. Products, variations, orders, customers and coupons have built-in
+/// templates, other resources echo the body with an ID unless [`MockServer::template`] sets one. Values the store
+/// would compute, such as order totals or the names of linked categories, are not filled in.
+///
+/// # Example
+///
+/// ```no_run
+/// use rust_woocommerce::testing::{Fault, MockServer};
+/// use rust_woocommerce::{Product, TaxClass};
+///
+/// #[tokio::main]
+/// async fn main() -> rust_woocommerce::Result<()> {
+///     let server = MockServer::start().await?;
+///     let client = server.client();
+///     let product: Product = client
+///         .create(Product::builder().name("Mug").regular_price("9.99").build())
+///         .await?;
+///     assert_eq!(client.retrieve::<Product>(product.id).await?.name, "Mug");
+///     server.inject(Fault::status(503).path("taxes"));
+///     assert!(client.list_all::<TaxClass>().await.is_ok());
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    task: JoinHandle<()>,
+}
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+#[derive(Debug, Default)]
+struct MockState {
+    origin: String,
+    collections: BTreeMap<String, Vec<Value>>,
+    templates: HashMap<String, Value>,
+    faults: Vec<Fault>,
+    latency: Duration,
+    requests: Vec<MockRequest>,
+}
+/// Request received by a [`MockServer`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockRequest {
+    /// HTTP method.
+    pub method: Method,
+    /// Path relative to the API namespace, without trailing slash, e.g. `products/7`.
+    pub path: String,
+    /// Query parameters, including the credentials or OAuth parameters added by the client.
+    pub query: Vec<(String, String)>,
+    /// Request body.
+    pub body: Option<Value>,
+}
+impl MockRequest {
+    /// Value of a query parameter
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+/// Error response injected into a [`MockServer`]
+#[derive(Debug, Clone)]
+pub struct Fault {
+    status: StatusCode,
+    method: Option<Method>,
+    path: Option<String>,
+    times: usize,
+    retry_after: Option<u64>,
+}
+impl Fault {
+    /// Answer the next matching request with the given status and a WooCommerce error body
+    pub fn status(status: u16) -> Self {
+        Fault {
+            status: StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            method: None,
+            path: None,
+            times: 1,
+            retry_after: None,
+        }
+    }
+    /// Only match requests with this method.
+    pub fn method(mut self, method: Method) -> Self {
+        let _ = self.method.insert(method);
+        self
+    }
+    /// Only match paths starting with this prefix, relative to the API namespace, e.g. `products`.
+    pub fn path(mut self, prefix: impl Into<String>) -> Self {
+        let _ = self.path.insert(prefix.into().trim_matches('/').to_owned());
+        self
+    }
+    /// Number of requests answered with the fault. Default is 1.
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = times;
+        self
+    }
+    /// Send a `Retry-After` header with the given number of seconds.
+    pub fn retry_after(mut self, seconds: u64) -> Self {
+        let _ = self.retry_after.insert(seconds);
+        self
+    }
+    fn matches(&self, method: &Method, path: &str) -> bool {
+        self.times > 0
+            && self.method.as_ref().map_or(true, |m| m == method)
+            && self.path.as_deref().map_or(true, |p| path.starts_with(p))
+    }
+    fn response(&self) -> Response {
+        let body = json!({
+            "code": "mock_injected_fault",
+            "message": "Injected fault",
+            "data": { "status": self.status.as_u16() }
+        });
+        let mut response = json_body(self.status, &body);
+        if let Some(seconds) = self.retry_after {
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(seconds));
+        }
+        response
+    }
+}
+impl MockServer {
+    /// Start a server on a random localhost port
+    pub async fn start() -> Result<Self> {
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0)).await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(MockState {
+            origin: format!("http://{addr}"),
+            ..MockState::default()
+        }));
+        let app = Router::new().fallback(handle).with_state(state.clone());
+        let task = tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, app).await {
+                tracing::error!("Mock server stopped: {e}");
+            }
+        });
+        Ok(MockServer { addr, state, task })
+    }
+    /// Address of the site, e.g. `http://127.0.0.1:49152/`
+    pub fn url(&self) -> String {
+        format!("http://{}/", self.addr)
+    }
+    /// Client builder pointed at the server with placeholder credentials
+    pub fn client_builder(&self) -> ApiClientBuilder {
+        ApiClient::builder()
+            .host(self.url())
+            .credentials(CONSUMER_KEY, CONSUMER_SECRET)
+    }
+    /// Client pointed at the server
+    pub fn client(&self) -> ApiClient {
+        self.client_builder()
+            .build()
+            .expect("mock server address is a valid host")
+    }
+    /// Add or replace an entity of type T
+    pub fn insert<T: Entity>(&self, entity: &T) -> Result<()> {
+        self.insert_json(&T::endpoint(), to_value(entity)?);
+        Ok(())
+    }
+    /// Add or replace an entity of type T belonging to a parent, e.g. a variation of a product
+    pub fn insert_child<T: Entity>(&self, parent_id: i32, entity: &T) -> Result<()> {
+        self.insert_json(&T::child_endpoint(parent_id), to_value(entity)?);
+        Ok(())
+    }
+    /// Add or replace a raw JSON entity in a collection, e.g. `data/currencies`
+    pub fn insert_json(&self, collection: &str, entity: Value) {
+        let mut state = self.lock();
+        let items = state
+            .collections
+            .entry(collection_key(collection))
+            .or_default();
+        match items
+            .iter_mut()
+            .find(|item| id_of(item).is_some() && id_of(item) == id_of(&entity))
+        {
+            Some(item) => *item = entity,
+            None => items.push(entity),
+        }
+    }
+    /// Entities of type T currently held by the server
+    pub fn entities<T: Entity>(&self) -> Result<Vec<T>> {
+        let items = self.json(&T::endpoint());
+        let payload = Value::Array(items);
+        serde_json::from_value(payload.clone()).map_err(|source| Error::Deserialize {
+            source,
+            payload: payload.to_string(),
+        })
+    }
+    /// Raw JSON entities of a collection
+    pub fn json(&self, collection: &str) -> Vec<Value> {
+        self.lock()
+            .collections
+            .get(&collection_key(collection))
+            .cloned()
+            .unwrap_or_default()
+    }
+    /// Template that created entities of type T are based on
+    pub fn template<T: Entity>(&self, template: Value) {
+        let endpoint = match T::endpoint() {
+            endpoint if endpoint.is_empty() => T::child_endpoint(0),
+            endpoint => endpoint,
+        };
+        self.lock()
+            .templates
+            .insert(template_key(&collection_key(&endpoint)), template);
+    }
+    /// Answer matching requests with an error, faults are tried in the order they were injected
+    pub fn inject(&self, fault: Fault) {
+        self.lock().faults.push(fault);
+    }
+    /// Delay every response by the given time
+    pub fn set_latency(&self, latency: Duration) {
+        self.lock().latency = latency;
+    }
+    /// Requests received so far
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.lock().requests.clone()
+    }
+    /// Forget the requests received so far
+    pub fn clear_requests(&self) {
+        self.lock().requests.clear();
+    }
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+fn to_value(entity: &impl serde::Serialize) -> Result<Value> {
+    serde_json::to_value(entity).map_err(|e| Error::Io(e.into()))
+}
+/// Collection path without namespace and surrounding slashes
+fn collection_key(path: &str) -> String {
+    path.trim_matches('/').to_owned()
+}
+/// Collection path with parent IDs replaced, e.g. `products/*/variations`
+fn template_key(collection: &str) -> String {
+    collection
+        .split('/')
+        .map(|segment| {
+            if segment.parse::<i64>().is_ok() {
+                "*"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+/// Identifier of an entity, `slug` and `code` stand in for resources without `id`
+fn id_of(entity: &Value) -> Option<String> {
+    ["id", "slug", "code"]
+        .iter()
+        .find_map(|key| match entity.get(*key)? {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+}
+fn error(status: StatusCode, code: &str, message: &str) -> Response {
+    let body = json!({ "code": code, "message": message, "data": { "status": status.as_u16() } });
+    json_body(status, &body)
+}
+fn json_body(status: StatusCode, body: &Value) -> Response {
+    (
+        status,
+        [(CONTENT_TYPE, "application/json")],
+        body.to_string(),
+    )
+        .into_response()
+}
+fn invalid_id() -> Response {
+    error(
+        StatusCode::NOT_FOUND,
+        "woocommerce_rest_invalid_id",
+        "Invalid ID.",
+    )
+}
+/// JSON response with an `ETag`, or 304 if the client already has it
+fn json_response(status: StatusCode, body: &Value, request_headers: &HeaderMap) -> Response {
+    let body = body.to_string();
+    let digest = Sha256::digest(body.as_bytes());
+    let etag: String = digest[..8].iter().map(|b| format!("{b:02x}")).collect();
+    let etag = format!("\"{etag}\"");
+    let mut response = if request_headers
+        .get(IF_NONE_MATCH)
+        .is_some_and(|v| v.as_bytes() == etag.as_bytes())
+    {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        (status, [(CONTENT_TYPE, "application/json")], body).into_response()
+    };
+    if let Ok(etag) = HeaderValue::try_from(etag) {
+        response.headers_mut().insert(ETAG, etag);
+    }
+    response
+}
+async fn handle(
+    State(state): State<Arc<Mutex<MockState>>>,
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let Some(path) = NAMESPACES
+        .iter()
+        .find_map(|namespace| uri.path().strip_prefix(namespace))
+    else {
+        return error(
+            StatusCode::NOT_FOUND,
+            "rest_no_route",
+            "No route was found matching the URL and request method.",
+        );
+    };
+    let path = collection_key(path);
+    let query: Vec<(String, String)> =
+        url::form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
+            .into_owned()
+            .collect();
+    let body = match body.is_empty() {
+        true => None,
+        false => match serde_json::from_slice::<Value>(&body) {
+            Ok(body) => Some(body),
+            Err(e) => return error(StatusCode::BAD_REQUEST, "rest_invalid_json", &e.to_string()),
+        },
+    };
+    let latency = {
+        let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err(message) = authenticate(&method, &state.origin, &uri, &headers, &query) {
+            return error(
+                StatusCode::UNAUTHORIZED,
+                "woocommerce_rest_authentication_error",
+                message,
+            );
+        }
+        state.requests.push(MockRequest {
+            method: method.clone(),
+            path: path.clone(),
+            query: query.clone(),
+            body: body.clone(),
+        });
+        state.latency
+    };
+    if !latency.is_zero() {
+        tokio::time::sleep(latency).await;
+    }
+    let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(fault) = state
+        .faults
+        .iter_mut()
+        .find(|fault| fault.matches(&method, &path))
+    {
+        fault.times -= 1;
+        let response = fault.response();
+        state.faults.retain(|fault| fault.times > 0);
+        return response;
+    }
+    let params: HashMap<String, String> = query.into_iter().collect();
+    let body = body.unwrap_or(Value::Null);
+    if let Some(collection) = path.strip_suffix("/batch") {
+        return match method {
+            Method::POST | Method::PUT | Method::PATCH => state.batch(collection, body),
+            _ => invalid_route(),
+        };
+    }
+    let is_collection = state.collections.contains_key(&path);
+    let item = path
+        .rsplit_once('/')
+        .filter(|(parent, id)| {
+            !is_collection
+                && state.collections.get(*parent).is_some_and(|items| {
+                    items.iter().any(|item| id_of(item).as_deref() == Some(*id))
+                })
+        })
+        .map(|(parent, id)| (parent.to_owned(), id.to_owned()));
+    match (method, item) {
+        (Method::GET, Some((parent, id))) => {
+            json_response(StatusCode::OK, &state.get(&parent, &id), &headers)
+        }
+        (Method::PUT | Method::PATCH | Method::POST, Some((parent, id))) => {
+            match state.update(&parent, &id, body) {
+                Some(updated) => json_response(StatusCode::OK, &updated, &HeaderMap::new()),
+                None => invalid_id(),
+            }
+        }
+        (Method::DELETE, Some((parent, id))) => match state.delete(&parent, &id) {
+            Some(deleted) => json_response(StatusCode::OK, &deleted, &HeaderMap::new()),
+            None => invalid_id(),
+        },
+        (Method::GET, None) => {
+            let last = path.rsplit('/').next().unwrap_or_default();
+            if !is_collection && last.parse::<i64>().is_ok() {
+                return invalid_id();
+            }
+            state.list(&path, &params, &headers)
+        }
+        (Method::POST, None) => {
+            let created = state.create(&path, body);
+            json_response(StatusCode::CREATED, &created, &HeaderMap::new())
+        }
+        (Method::PUT, None) if body.is_array() => {
+            state
+                .collections
+                .insert(path, body.as_array().cloned().unwrap_or_default());
+            json_response(StatusCode::OK, &body, &HeaderMap::new())
+        }
+        (Method::PUT | Method::PATCH | Method::DELETE, None) => invalid_id(),
+        _ => invalid_route(),
+    }
+}
+/// Check the credentials of a request like `WC_REST_Authentication` does
+fn authenticate(
+    method: &Method,
+    origin: &str,
+    uri: &Uri,
+    headers: &HeaderMap,
+    query: &[(String, String)],
+) -> std::result::Result<(), &'static str> {
+    let param = |name: &str| {
+        query
+            .iter()
+            .rev()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    };
+    if let Some(basic) = headers
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Basic "))
+    {
+        let expected = STANDARD.encode(format!("{CONSUMER_KEY}:{CONSUMER_SECRET}"));
+        return match basic == expected {
+            true => Ok(()),
+            false => Err("Consumer secret is invalid."),
+        };
+    }
+    if let Some(key) = param("consumer_key") {
+        return match key == CONSUMER_KEY && param("consumer_secret") == Some(CONSUMER_SECRET) {
+            true => Ok(()),
+            false => Err("Consumer secret is invalid."),
+        };
+    }
+    let Some(signature) = param("oauth_signature") else {
+        return Err("Missing OAuth parameter oauth_signature");
+    };
+    if param("oauth_consumer_key") != Some(CONSUMER_KEY) {
+        return Err("Consumer key is invalid.");
+    }
+    let params: BTreeMap<&str, &str> = query
+        .iter()
+        .filter(|(k, _)| k != "oauth_signature")
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    let encode = |s: &str| utf8_percent_encode(s, RAWURLENCODE).to_string();
+    let query_string = params
+        .iter()
+        .map(|(k, v)| format!("{}%3D{}", encode(k), encode(v)))
+        .collect::<Vec<_>>()
+        .join("%26");
+    let string_to_sign = format!(
+        "{}&{}&{query_string}",
+        method.as_str(),
+        encode(&format!("{origin}{}", uri.path()))
+    );
+    let key = format!("{CONSUMER_SECRET}&");
+    let expected = match param("oauth_signature_method") {
+        Some("HMAC-SHA1") => {
+            let mut mac = Hmac::<sha1::Sha1>::new_from_slice(key.as_bytes()).expect("any key size");
+            mac.update(string_to_sign.as_bytes());
+            STANDARD.encode(mac.finalize().into_bytes())
+        }
+        Some("HMAC-SHA256") => {
+            let mut mac = Hmac::<Sha256>::new_from_slice(key.as_bytes()).expect("any key size");
+            mac.update(string_to_sign.as_bytes());
+            STANDARD.encode(mac.finalize().into_bytes())
+        }
+        _ => return Err("Invalid signature - signature method is invalid."),
+    };
+    match signature == expected {
+        true => Ok(()),
+        false => Err("Invalid signature - provided signature does not match."),
+    }
+}
+fn invalid_route() -> Response {
+    error(
+        StatusCode::NOT_FOUND,
+        "rest_no_route",
+        "No route was found matching the URL and request method.",
+    )
+}
+fn now() -> Value {
+    Value::String(
+        Utc::now()
+            .naive_utc()
+            .format("%Y-%m-%dT%H:%M:%S")
+            .to_string(),
+    )
+}
+/// Set the fields of `target` that are present, nested objects such as addresses are merged field by field.
+/// Without `keep_unknown` fields `target` does not have are dropped, like a template does.
+fn merge(target: &mut Map<String, Value>, changes: Value, keep_unknown: bool) {
+    if let Value::Object(changes) = changes {
+        for (key, value) in changes {
+            match (target.get_mut(&key), value) {
+                (Some(Value::Object(nested)), value @ Value::Object(_)) => {
+                    merge(nested, value, keep_unknown)
+                }
+                (None, _) if !keep_unknown => {}
+                (_, value) => {
+                    target.insert(key, value);
+                }
+            }
+        }
+    }
+}
+fn touch(entity: &mut Map<String, Value>, keys: &[&str]) {
+    for key in keys {
+        if entity.contains_key(*key) {
+            entity.insert((*key).to_owned(), now());
+        }
+    }
+}
+impl MockState {
+    fn get(&self, collection: &str, id: &str) -> Value {
+        self.collections
+            .get(collection)
+            .and_then(|items| items.iter().find(|item| id_of(item).as_deref() == Some(id)))
+            .cloned()
+            .unwrap_or(Value::Null)
+    }
+    fn create(&mut self, collection: &str, body: Value) -> Value {
+        let template = self
+            .templates
+            .get(&template_key(collection))
+            .cloned()
+            .or_else(|| builtin_template(&template_key(collection)));
+        let mut entity = match &template {
+            Some(Value::Object(template)) => template.clone(),
+            _ => Map::new(),
+        };
+        merge(&mut entity, body, template.is_none());
+        let items = self.collections.entry(collection.to_owned()).or_default();
+        if id_of(&Value::Object(entity.clone())).map_or(true, |id| id == "0") {
+            let next_id = items
+                .iter()
+                .filter_map(|item| item.get("id")?.as_i64())
+                .max()
+                .unwrap_or(0)
+                + 1;
+            entity.insert(String::from("id"), Value::from(next_id));
+        }
+        touch(
+            &mut entity,
+            &[
+                "date_created",
+                "date_created_gmt",
+                "date_modified",
+                "date_modified_gmt",
+            ],
+        );
+        let entity = Value::Object(entity);
+        items.push(entity.clone());
+        entity
+    }
+    fn update(&mut self, collection: &str, id: &str, body: Value) -> Option<Value> {
+        let item = self
+            .collections
+            .get_mut(collection)?
+            .iter_mut()
+            .find(|item| id_of(item).as_deref() == Some(id))?;
+        let Value::Object(entity) = item else {
+            return None;
+        };
+        let mut body = body;
+        if let Value::Object(changes) = &mut body {
+            changes.remove("id");
+        }
+        merge(entity, body, true);
+        touch(entity, &["date_modified", "date_modified_gmt"]);
+        Some(item.clone())
+    }
+    fn delete(&mut self, collection: &str, id: &str) -> Option<Value> {
+        let items = self.collections.get_mut(collection)?;
+        let index = items
+            .iter()
+            .position(|item| id_of(item).as_deref() == Some(id))?;
+        Some(items.remove(index))
+    }
+    fn batch(&mut self, collection: &str, body: Value) -> Response {
+        let invalid = |id: &Value| {
+            json!({
+                "id": id,
+                "error": { "code": "woocommerce_rest_invalid_id", "message": "Invalid ID.", "data": { "status": 404 } }
+            })
+        };
+        let mut result = Map::new();
+        let section = |name: &str| {
+            body.get(name)
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default()
+        };
+        let (create, update, delete) = (section("create"), section("update"), section("delete"));
+        if body.get("create").is_some() {
+            let created = create
+                .into_iter()
+                .map(|entity| self.create(collection, entity))
+                .collect();
+            result.insert(String::from("create"), Value::Array(created));
+        }
+        if body.get("update").is_some() {
+            let updated = update
+                .into_iter()
+                .map(|entity| {
+                    let id = entity.get("id").cloned().unwrap_or(Value::Null);
+                    let key = id_of(&json!({ "id": id })).unwrap_or_default();
+                    self.update(collection, &key, entity)
+                        .unwrap_or_else(|| invalid(&id))
+                })
+                .collect();
+            result.insert(String::from("update"), Value::Array(updated));
+        }
+        if body.get("delete").is_some() {
+            let deleted = delete
+                .into_iter()
+                .map(|id| {
+                    let key = id_of(&json!({ "id": id })).unwrap_or_default();
+                    self.delete(collection, &key)
+                        .unwrap_or_else(|| invalid(&id))
+                })
+                .collect();
+            result.insert(String::from("delete"), Value::Array(deleted));
+        }
+        json_response(StatusCode::OK, &Value::Object(result), &HeaderMap::new())
+    }
+    fn list(
+        &self,
+        collection: &str,
+        params: &HashMap<String, String>,
+        headers: &HeaderMap,
+    ) -> Response {
+        let mut items = self
+            .collections
+            .get(collection)
+            .cloned()
+            .unwrap_or_default();
+        let ids = |name: &str| -> Option<Vec<String>> {
+            params
+                .get(name)
+                .map(|ids| ids.split(',').map(|id| id.trim().to_owned()).collect())
+        };
+        if let Some(include) = ids("include") {
+            items.retain(|item| id_of(item).is_some_and(|id| include.contains(&id)));
+        }
+        if let Some(exclude) = ids("exclude") {
+            items.retain(|item| id_of(item).map_or(true, |id| !exclude.contains(&id)));
+        }
+        for field in ["status", "sku", "email", "code", "slug"] {
+            if let Some(wanted) = params.get(field).filter(|v| *v != "any") {
+                items.retain(|item| item.get(field).and_then(Value::as_str) == Some(wanted));
+            }
+        }
+        if let Some(search) = params.get("search").map(|s| s.to_lowercase()) {
+            items.retain(|item| {
+                ["name", "sku", "email", "code", "first_name", "last_name"]
+                    .iter()
+                    .filter_map(|field| item.get(*field)?.as_str())
+                    .any(|value| value.to_lowercase().contains(&search))
+            });
+        }
+        let gmt = params.get("dates_are_gmt").is_some_and(|v| v == "true");
+        let suffix = if gmt { "_gmt" } else { "" };
+        let date_filters = [
+            ("after", "date_created", true),
+            ("before", "date_created", false),
+            ("modified_after", "date_modified", true),
+            ("modified_before", "date_modified", false),
+        ];
+        for (param, field, after) in date_filters {
+            if let Some(limit) = params.get(param) {
+                let field = format!("{field}{suffix}");
+                let limit = &limit[..limit.len().min(19)];
+                items.retain(|item| match item.get(&field).and_then(Value::as_str) {
+                    Some(date) if after => &date[..date.len().min(19)] > limit,
+                    Some(date) => &date[..date.len().min(19)] < limit,
+                    None => true,
+                });
+            }
+        }
+        if let Some(orderby) = params.get("orderby") {
+            let field = match orderby.as_str() {
+                "date" => "date_created_gmt",
+                "modified" => "date_modified_gmt",
+                "title" => "name",
+                other => other,
+            };
+            items.sort_by(|a, b| {
+                let (a, b) = (a.get(field), b.get(field));
+                match (a.and_then(Value::as_f64), b.and_then(Value::as_f64)) {
+                    (Some(a), Some(b)) => a.total_cmp(&b),
+                    _ => a.and_then(Value::as_str).cmp(&b.and_then(Value::as_str)),
+                }
+            });
+            if params.get("order").map_or(true, |order| order != "asc") {
+                items.reverse();
+            }
+        }
+        let per_page = params
+            .get("per_page")
+            .and_then(|p| p.parse::<usize>().ok())
+            .unwrap_or(DEFAULT_PER_PAGE)
+            .clamp(1, 100);
+        let page = params
+            .get("page")
+            .and_then(|p| p.parse::<usize>().ok())
+            .unwrap_or(1)
+            .max(1);
+        let total = items.len();
+        let total_pages = total.div_ceil(per_page);
+        let mut page_items: Vec<Value> = items
+            .into_iter()
+            .skip((page - 1) * per_page)
+            .take(per_page)
+            .collect();
+        if let Some(fields) = params.get("_fields") {
+            let fields: Vec<&str> = fields.split(',').map(str::trim).collect();
+            for item in &mut page_items {
+                if let Value::Object(entity) = item {
+                    entity.retain(|key, _| fields.contains(&key.as_str()));
+                }
+            }
+        }
+        let mut response = json_response(StatusCode::OK, &Value::Array(page_items), headers);
+        let response_headers = response.headers_mut();
+        response_headers.insert("X-WP-Total", HeaderValue::from(total));
+        response_headers.insert("X-WP-TotalPages", HeaderValue::from(total_pages));
+        let link = |page: usize, rel: &str| {
+            let mut query = url::form_urlencoded::Serializer::new(String::new());
+            for (name, value) in params.iter().filter(|(name, _)| *name != "page") {
+                query.append_pair(name, value);
+            }
+            query.append_pair("page", &page.to_string());
+            format!(
+                "<{}{}{collection}/?{}>; rel=\"{rel}\"",
+                self.origin,
+                NAMESPACES[0],
+                query.finish()
+            )
+        };
+        let mut links = Vec::new();
+        if page > 1 {
+            links.push(link(page - 1, "prev"));
+        }
+        if page < total_pages {
+            links.push(link(page + 1, "next"));
+        }
+        if let Ok(links) = HeaderValue::try_from(links.join(", ")) {
+            if !links.is_empty() {
+                response_headers.insert(LINK, links);
+            }
+        }
+        response
+    }
+}
+/// Fields of both objects
+fn join(first: Value, second: Value) -> Value {
+    let mut joined = first;
+    if let (Value::Object(joined), Value::Object(second)) = (&mut joined, second) {
+        joined.extend(second);
+    }
+    joined
+}
+/// Built-in templates, with every field of the models and the defaults of a fresh WooCommerce store
+fn builtin_template(key: &str) -> Option<Value> {
+    let address = json!({
+        "first_name": "", "last_name": "", "company": "", "address_1": "", "address_2": "",
+        "city": "", "state": "", "postcode": "", "country": ""
+    });
+    let mut billing = address.clone();
+    if let Value::Object(billing) = &mut billing {
+        billing.insert(String::from("email"), Value::from(""));
+        billing.insert(String::from("phone"), Value::from(""));
+    }
+    let template = match key {
+        // Split in two, the whole object exceeds the recursion limit of `json!`
+        "products" => join(
+            json!({
+                "id": 0, "name": "", "slug": "", "permalink": "",
+                "date_created": null, "date_created_gmt": null, "date_modified": null, "date_modified_gmt": null,
+                "type": "simple", "status": "publish", "featured": false, "catalog_visibility": "visible",
+                "description": "", "short_description": "", "sku": "", "price": "", "regular_price": "", "sale_price": "",
+                "date_on_sale_from": null, "date_on_sale_from_gmt": null, "date_on_sale_to": null, "date_on_sale_to_gmt": null,
+                "price_html": "", "on_sale": false, "purchasable": true, "total_sales": 0, "virtual": false
+            }),
+            json!({
+                "downloadable": false, "downloads": [], "download_limit": -1, "download_expiry": -1,
+                "external_url": "", "button_text": "", "tax_status": "taxable", "tax_class": "",
+                "manage_stock": false, "stock_quantity": null, "stock_status": "instock", "backorders": "no",
+                "backorders_allowed": false, "backordered": false, "sold_individually": false, "weight": "",
+                "dimensions": { "length": "", "width": "", "height": "" }, "shipping_required": true,
+                "shipping_taxable": true, "shipping_class": "", "shipping_class_id": 0, "reviews_allowed": true,
+                "average_rating": "0", "rating_count": 0, "related_ids": [], "upsell_ids": [], "cross_sell_ids": [],
+                "parent_id": 0, "purchase_note": "", "categories": [], "tags": [], "images": [], "attributes": [],
+                "default_attributes": [], "variations": [], "grouped_products": [], "menu_order": 0, "meta_data": []
+            }),
+        ),
+        "products/*/variations" => json!({
+            "id": 0, "date_created": null, "date_created_gmt": null, "date_modified": null, "date_modified_gmt": null,
+            "description": "", "permalink": "", "sku": "", "price": "", "regular_price": "", "sale_price": "",
+            "date_on_sale_from": null, "date_on_sale_from_gmt": null, "date_on_sale_to": null, "date_on_sale_to_gmt": null,
+            "on_sale": false, "status": "publish", "purchasable": true, "virtual": false, "downloadable": false,
+            "downloads": [], "download_limit": -1, "download_expiry": -1, "tax_status": "taxable", "tax_class": "",
+            "manage_stock": false, "stock_quantity": null, "stock_status": "instock", "backorders": "no",
+            "backorders_allowed": false, "backordered": false, "weight": "",
+            "dimensions": { "length": "", "width": "", "height": "" }, "shipping_class": "", "shipping_class_id": 0,
+            "image": null, "attributes": [], "menu_order": 0, "meta_data": []
+        }),
+        "orders" => json!({
+            "id": 0, "parent_id": 0, "number": "", "order_key": "", "created_via": "rest-api", "version": "",
+            "status": "pending", "currency": "USD",
+            "date_created": null, "date_created_gmt": null, "date_modified": null, "date_modified_gmt": null,
+            "discount_total": "0.00", "discount_tax": "0.00", "shipping_total": "0.00", "shipping_tax": "0.00",
+            "cart_tax": "0.00", "total": "0.00", "total_tax": "0.00", "prices_include_tax": false, "customer_id": 0,
+            "customer_ip_address": "", "customer_user_agent": "", "customer_note": "",
+            "billing": billing, "shipping": address, "payment_method": "", "payment_method_title": "",
+            "transaction_id": "", "date_paid": null, "date_paid_gmt": null, "date_completed": null,
+            "date_completed_gmt": null, "cart_hash": "", "meta_data": [], "line_items": [], "tax_lines": [],
+            "shipping_lines": [], "fee_lines": [], "coupon_lines": [], "refunds": []
+        }),
+        "customers" => json!({
+            "id": 0, "date_created": null, "date_created_gmt": null, "date_modified": null, "date_modified_gmt": null,
+            "email": "", "first_name": "", "last_name": "", "role": "customer", "username": "",
+            "billing": billing, "shipping": address, "is_paying_customer": false, "avatar_url": "", "meta_data": []
+        }),
+        "coupons" => json!({
+            "id": 0, "code": "", "amount": "0", "date_created": null, "date_created_gmt": null,
+            "date_modified": null, "date_modified_gmt": null, "discount_type": "fixed_cart", "description": "",
+            "date_expires": null, "date_expires_gmt": null, "usage_count": 0, "individual_use": false,
+            "product_ids": [], "excluded_product_ids": [], "usage_limit": null, "usage_limit_per_user": null,
+            "limit_usage_to_x_items": null, "free_shipping": false, "product_categories": [],
+            "excluded_product_categories": [], "exclude_sale_items": false, "minimum_amount": "0.00",
+            "maximum_amount": "0.00", "email_restrictions": [], "used_by": [], "meta_data": []
+        }),
+        _ => return None,
+    };
+    Some(template)
+}
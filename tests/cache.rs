@@ -0,0 +1,83 @@
+//! Checks of the response cache stores and TTLs.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{TimeDelta, Utc};
+use rust_woocommerce::{
+    ApiClient, CacheStore, CachedResponse, DiskCache, Entity, Interaction, MemoryCache,
+    ReplayTransport, ResponseCache, TaxClass,
+};
+
+#[tokio::test]
+async fn cache_stores() {
+    let response = |url: &str, resource: &str| CachedResponse {
+        url: url.to_owned(),
+        resource: resource.to_owned(),
+        status: 200,
+        headers: vec![(String::from("X-WP-TotalPages"), String::from("1"))],
+        body: String::from("[]"),
+        stored_at: Utc::now(),
+    };
+    let classes = response(
+        "https://shop.example.com/wp-json/wc/v3/taxes/classes/",
+        "taxes",
+    );
+    let gateways = response(
+        "https://shop.example.com/wp-json/wc/v3/payment_gateways/",
+        "payment_gateways",
+    );
+    let dir = std::env::temp_dir().join(format!("woo-cache-{}", std::process::id()));
+    let stores: [Box<dyn CacheStore>; 2] =
+        [Box::new(MemoryCache::new()), Box::new(DiskCache::new(&dir))];
+    for store in stores {
+        assert_eq!(store.get("taxes", &classes.url).await.unwrap(), None);
+        store.put(&classes).await.unwrap();
+        store.put(&gateways).await.unwrap();
+        let cached = store.get("taxes", &classes.url).await.unwrap().unwrap();
+        assert_eq!(cached.header("x-wp-totalpages"), Some("1"));
+        store.invalidate("taxes").await.unwrap();
+        assert_eq!(store.get("taxes", &classes.url).await.unwrap(), None);
+        assert_eq!(
+            store.get("payment_gateways", &gateways.url).await.unwrap(),
+            Some(gateways.clone())
+        );
+    }
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn cached_in_the_future() {
+    let path = format!("/wp-json/wc/v3/{}?page=1&per_page=50", TaxClass::endpoint());
+    // A clock that went backwards must not make the entry fresh forever
+    let store = Arc::new(MemoryCache::new());
+    store
+        .put(&CachedResponse {
+            url: format!("https://shop.example.com{path}"),
+            resource: String::from("taxes"),
+            status: 200,
+            headers: Vec::new(),
+            body: String::from("[]"),
+            stored_at: Utc::now() + TimeDelta::hours(1),
+        })
+        .await
+        .unwrap();
+    let replay = Arc::new(ReplayTransport::new(vec![Interaction {
+        method: String::from("GET"),
+        path,
+        request_body: None,
+        status: 200,
+        headers: Vec::new(),
+        body: serde_json::json!([{"slug": "standard", "name": "Standard"}]),
+    }]));
+    let client = ApiClient::builder()
+        .host("shop.example.com")
+        .credentials("ck", "cs")
+        .cache(ResponseCache::new(store).ttl::<TaxClass>(Duration::from_secs(60)))
+        .transport(replay.clone())
+        .build()
+        .unwrap();
+    let classes = client.list_all::<TaxClass>().await.unwrap();
+    assert_eq!(classes.len(), 1);
+    assert!(replay.unused().is_empty());
+}
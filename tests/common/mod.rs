@@ -0,0 +1,17 @@
+//! Helpers shared by the integration tests.
+
+/// First entity recorded in a fixture, without the given fields
+pub fn fixture_entity(fixture: &str, without: &[&str]) -> serde_json::Value {
+    let path = format!(
+        "{}/tests/fixtures/{fixture}.json",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let interactions: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    let mut entity = interactions[0]["body"][0].clone();
+    let fields = entity.as_object_mut().unwrap();
+    for field in without {
+        fields.remove(*field);
+    }
+    entity
+}
@@ -0,0 +1,72 @@
+//! Checks of the UTC date accessors and builder inputs backed by the `_gmt` fields.
+
+use chrono::{FixedOffset, TimeDelta, TimeZone, Utc};
+use rust_woocommerce::{Coupon, Customer, Product, ProductVariation, Syncable};
+use serde::Serialize;
+
+mod common;
+use common::fixture_entity;
+
+fn json(value: &impl Serialize) -> serde_json::Value {
+    serde_json::to_value(value).unwrap()
+}
+
+#[test]
+fn gmt_fields() {
+    let coupon: Coupon = serde_json::from_value(serde_json::json!({
+        "id": 720, "code": "summer", "amount": "10.00",
+        "date_created": "2017-03-21T15:25:02", "date_created_gmt": "2017-03-21T18:25:02",
+        "date_modified": "2017-03-21T15:25:02", "date_modified_gmt": "2017-03-21T18:25:02",
+        "discount_type": "percent", "description": "",
+        "date_expires": "2024-01-31T00:00:00", "date_expires_gmt": "2024-01-31T03:00:00",
+        "usage_count": 0, "individual_use": false, "product_ids": [], "excluded_product_ids": [],
+        "usage_limit": null, "usage_limit_per_user": null, "limit_usage_to_x_items": null,
+        "free_shipping": false, "product_categories": [], "excluded_product_categories": [],
+        "exclude_sale_items": false, "minimum_amount": "0.00", "maximum_amount": "0.00",
+        "email_restrictions": [], "used_by": [], "meta_data": []
+    }))
+    .unwrap();
+    assert_eq!(
+        coupon.date_created_utc(),
+        Utc.with_ymd_and_hms(2017, 3, 21, 18, 25, 2).single()
+    );
+    assert_eq!(
+        coupon.date_expires_utc(),
+        Utc.with_ymd_and_hms(2024, 1, 31, 3, 0, 0).single()
+    );
+    let sale_start = FixedOffset::west_opt(3 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(2024, 11, 29, 0, 0, 0)
+        .unwrap()
+        + TimeDelta::milliseconds(250);
+    let product = Product::builder()
+        .date_on_sale_from(2024, 11, 28)
+        .date_on_sale_from_utc(sale_start)
+        .build();
+    assert_eq!(
+        json(&product)["date_on_sale_from_gmt"],
+        "2024-11-29T03:00:00"
+    );
+    assert!(json(&product).get("date_on_sale_from").is_none());
+    let variation = ProductVariation::builder()
+        .date_on_sale_to_utc(sale_start)
+        .build();
+    assert_eq!(
+        json(&variation)["date_on_sale_to_gmt"],
+        "2024-11-29T03:00:00"
+    );
+    let update = Coupon::update().date_expires_utc(sale_start).build();
+    assert_eq!(json(&update)["date_expires_gmt"], "2024-11-29T03:00:00");
+
+    // Entities created by some importers have no modification date yet
+    let mut customer = fixture_entity("customers", &[]);
+    customer["date_modified"] = "".into();
+    customer["date_modified_gmt"] = serde_json::Value::Null;
+    let customer: Customer = serde_json::from_value(customer).unwrap();
+    assert_eq!(customer.date_modified, None);
+    assert_eq!(customer.date_modified_utc(), None);
+    assert_eq!(
+        Syncable::modified_utc(&customer),
+        customer.date_created_utc().unwrap()
+    );
+}
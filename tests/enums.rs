@@ -0,0 +1,75 @@
+//! Checks that enums keep values this crate does not know and write them back unchanged.
+
+use rust_woocommerce::webhooks::{Event, Resource, Topic, WebhookStatus};
+use rust_woocommerce::{
+    CurrencyISO, DiscountType, LocationType, OrderStatus, ProductType, Role, StockStatus,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::json;
+
+fn json(value: &impl Serialize) -> serde_json::Value {
+    serde_json::to_value(value).unwrap()
+}
+
+#[test]
+fn other_variants() {
+    fn round_trip<T>(raw: &str, expected: T)
+    where
+        T: DeserializeOwned + Serialize + PartialEq + std::fmt::Debug,
+    {
+        let parsed: T = serde_json::from_value(json!(raw)).unwrap();
+        assert_eq!(parsed, expected);
+        assert_eq!(json(&parsed), raw);
+    }
+    round_trip("on-hold", OrderStatus::OnHold);
+    round_trip("shipped", OrderStatus::Other(String::from("shipped")));
+    round_trip(
+        "subscription",
+        ProductType::Other(String::from("subscription")),
+    );
+    round_trip("onbackorder", StockStatus::Onbackorder);
+    round_trip("preorder", StockStatus::Other(String::from("preorder")));
+    round_trip("shop_manager", Role::ShopManager);
+    round_trip(
+        "wholesale_customer",
+        Role::Other(String::from("wholesale_customer")),
+    );
+    round_trip(
+        "store_credit",
+        DiscountType::Other(String::from("store_credit")),
+    );
+    round_trip("EUR", CurrencyISO::EUR);
+    round_trip("XBT", CurrencyISO::Other(String::from("XBT")));
+    round_trip("region", LocationType::Other(String::from("region")));
+    assert_eq!(OrderStatus::from("completed"), OrderStatus::Completed);
+    assert_eq!(ProductType::Variable.to_string(), "variable");
+    let status: WebhookStatus = serde_json::from_value(json!("archived")).unwrap();
+    assert_eq!(status, WebhookStatus::Other(String::from("archived")));
+    assert_eq!(json(&status), "archived");
+}
+
+#[test]
+fn webhook_topics() {
+    for (raw, topic) in [
+        (
+            "order.restored",
+            Topic::Resource(Resource::Order, Event::Restored),
+        ),
+        (
+            "action.woocommerce_add_to_cart",
+            Topic::action("woocommerce_add_to_cart"),
+        ),
+        (
+            "subscription.switched",
+            Topic::Resource(
+                Resource::Other(String::from("subscription")),
+                Event::Other(String::from("switched")),
+            ),
+        ),
+        ("custom", Topic::Other(String::from("custom"))),
+    ] {
+        let parsed: Topic = serde_json::from_value(json!(raw)).unwrap();
+        assert_eq!(parsed, topic);
+        assert_eq!(json(&parsed), raw);
+    }
+}
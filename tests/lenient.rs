@@ -0,0 +1,62 @@
+//! Checks that models tolerate WooCommerce quirks and keep unknown fields, unless read in strict mode.
+
+use rust_woocommerce::{strict, Coupon};
+use serde::Serialize;
+
+mod common;
+use common::fixture_entity;
+
+fn json(value: &impl Serialize) -> serde_json::Value {
+    serde_json::to_value(value).unwrap()
+}
+
+fn quirky_coupon() -> serde_json::Value {
+    serde_json::json!({
+        "id": 720, "code": "free shipping", "amount": "0.00",
+        "date_created": "2017-03-21T15:25:02", "date_created_gmt": "2017-03-21T18:25:02",
+        "date_modified": "2017-03-21T15:25:02", "date_modified_gmt": "2017-03-21T18:25:02",
+        "discount_type": "fixed_cart", "description": null,
+        "date_expires": "", "date_expires_gmt": false,
+        "usage_count": 0, "individual_use": true, "product_ids": [], "excluded_product_ids": [],
+        "usage_limit": "5", "usage_limit_per_user": null, "limit_usage_to_x_items": false,
+        "free_shipping": true, "product_categories": [], "excluded_product_categories": [],
+        "exclude_sale_items": false, "minimum_amount": 0, "maximum_amount": "0.00",
+        "email_restrictions": [], "used_by": [], "meta_data": [],
+        "_wjecf_is_auto_coupon": "yes"
+    })
+}
+
+#[test]
+fn lenient_models() {
+    let coupon: Coupon = serde_json::from_value(quirky_coupon()).unwrap();
+    assert_eq!(coupon.description, "");
+    assert_eq!(coupon.date_expires, None);
+    assert_eq!(coupon.date_expires_gmt, None);
+    assert_eq!(coupon.usage_limit, Some(5));
+    assert_eq!(coupon.limit_usage_to_x_items, None);
+    assert!(coupon.minimum_amount.is_zero());
+    assert_eq!(json(&coupon)["maximum_amount"], "0.00");
+    assert_eq!(coupon.extra["_wjecf_is_auto_coupon"], "yes");
+    assert_eq!(json(&coupon)["_wjecf_is_auto_coupon"], "yes");
+}
+
+#[test]
+fn strict_models() {
+    let error = strict::from_value::<Coupon>(quirky_coupon()).unwrap_err();
+    assert!(error.to_string().contains("expected a string"), "{error}");
+    let mut coupon = fixture_entity("coupons", &[]);
+    coupon["_embedded"] = serde_json::json!({});
+    let coupon: Coupon = strict::from_value(coupon.clone()).unwrap();
+    assert!(coupon.extra.get("_links").is_some());
+    let mut unknown = json(&coupon);
+    unknown["_wjecf_is_auto_coupon"] = "yes".into();
+    let error = strict::from_value::<Coupon>(unknown.clone()).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("unknown fields: _wjecf_is_auto_coupon"),
+        "{error}"
+    );
+    // Strict mode only lasts for the call
+    assert!(serde_json::from_value::<Coupon>(unknown).is_ok());
+}
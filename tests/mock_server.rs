@@ -0,0 +1,878 @@
+//! End-to-end checks of the client against the mock server, run with `--features testing`.
+#![cfg(feature = "testing")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{NaiveDate, TimeDelta};
+use futures::{future::BoxFuture, StreamExt, TryStreamExt};
+use reqwest::header::AUTHORIZATION;
+use rust_woocommerce::testing::{Fault, MockServer};
+use rust_woocommerce::{
+    ApiClient, ApiNamespace, Auth, Category, Change, Coupon, Customer, DiscountType, Error,
+    MemoryCheckpointStore, Order, OrderStatus, Product, ProductSortBy, ProductVariation,
+    RecordingTransport, ReplayTransport, ResponseCache, Result, RetryPolicy, SignatureMethod,
+    SortOrder, StreamOptions, SyncEngine, Transport,
+};
+
+fn fast_retries() -> RetryPolicy {
+    RetryPolicy::default()
+        .max_attempts(3)
+        .base_delay(Duration::from_millis(1))
+}
+
+async fn seed_products(server: &MockServer, count: usize) -> Vec<Product> {
+    let products = (1..=count)
+        .map(|i| {
+            Product::builder()
+                .name(format!("Product {i}"))
+                .sku(format!("SKU-{i}"))
+                .regular_price("10.00")
+                .build()
+        })
+        .collect();
+    server
+        .client()
+        .batch_create::<Product, _>(products)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn pagination() {
+    let server = MockServer::start().await.unwrap();
+    seed_products(&server, 250).await;
+    let client = server.client();
+    server.clear_requests();
+    let all = client.list_all::<Product>().await.unwrap();
+    assert_eq!(all.len(), 250);
+    let requests = server.requests();
+    let per_page: usize = requests[0].param("per_page").unwrap().parse().unwrap();
+    assert_eq!(requests.len(), 250usize.div_ceil(per_page));
+    let mut pages: Vec<&str> = requests.iter().filter_map(|r| r.param("page")).collect();
+    pages.sort_unstable();
+    pages.dedup();
+    assert_eq!(pages.len(), requests.len());
+
+    let options = StreamOptions::default().per_page(7).prefetch(2);
+    let streamed: Vec<Product> = client
+        .stream_with_options(Product::query(), options)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(streamed.len(), 250);
+    let found = client
+        .list::<Product>(Product::query().sku("SKU-13"))
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].name, "Product 13");
+}
+
+/// Makes the server fail the second page of a listing
+#[derive(Debug)]
+struct FailPage2 {
+    server: Arc<MockServer>,
+    inner: reqwest::Client,
+}
+impl Transport for FailPage2 {
+    fn execute(&self, request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>> {
+        let page2 = request
+            .url()
+            .query_pairs()
+            .any(|(k, v)| k == "page" && v == "2");
+        if page2 {
+            self.server.inject(Fault::status(500).times(1));
+        }
+        Transport::execute(&self.inner, request)
+    }
+}
+
+#[tokio::test]
+async fn partial_list() {
+    let server = Arc::new(MockServer::start().await.unwrap());
+    seed_products(&server, 150).await;
+    let transport = FailPage2 {
+        server: server.clone(),
+        inner: reqwest::Client::new(),
+    };
+    // One request at a time, so the injected fault is served to the second page
+    let client = server
+        .client_builder()
+        .transport(transport)
+        .retry_policy(RetryPolicy::none())
+        .concurrency_limit(1)
+        .build()
+        .unwrap();
+    let partial = client.list_all_partial::<Product>().await.unwrap();
+    assert!(!partial.is_complete());
+    assert_eq!(partial.errors.len(), 1);
+    assert_eq!(partial.errors[0].page, 2);
+    assert_eq!(
+        partial.errors[0].error.status().map(|s| s.as_u16()),
+        Some(500)
+    );
+    assert_eq!(partial.items.len(), 100);
+    assert_eq!(partial.items[0].name, "Product 1");
+    assert_eq!(partial.items[50].name, "Product 101");
+
+    match client.list_all::<Product>().await {
+        Err(Error::Pages(errors)) => {
+            let pages: Vec<i32> = errors.iter().map(|e| e.page).collect();
+            assert_eq!(pages, vec![2]);
+        }
+        other => panic!("expected a page error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn lazy_stream() {
+    let server = Arc::new(MockServer::start().await.unwrap());
+    seed_products(&server, 30).await;
+    let client = server.client();
+    server.clear_requests();
+    let options = StreamOptions::default().per_page(10);
+    let first: Vec<Product> = client
+        .stream_with_options(Product::query(), options)
+        .take(15)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(first.len(), 15);
+    assert_eq!(first[14].name, "Product 15");
+    // The third page is never requested because the consumer stopped early
+    let requests = server.requests();
+    let pages: Vec<&str> = requests.iter().filter_map(|r| r.param("page")).collect();
+    assert_eq!(pages, vec!["1", "2"]);
+    assert!(requests.iter().all(|r| r.param("per_page") == Some("10")));
+
+    let transport = FailPage2 {
+        server: server.clone(),
+        inner: reqwest::Client::new(),
+    };
+    let client = server
+        .client_builder()
+        .transport(transport)
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+    let results: Vec<Result<Product>> = client
+        .stream_with_options(Product::query(), options)
+        .collect()
+        .await;
+    assert_eq!(results.len(), 11);
+    assert!(results[..10].iter().all(|r| r.is_ok()));
+    assert_eq!(
+        results[10]
+            .as_ref()
+            .unwrap_err()
+            .status()
+            .map(|s| s.as_u16()),
+        Some(500)
+    );
+}
+
+#[tokio::test]
+async fn query_builders() {
+    let server = MockServer::start().await.unwrap();
+    let ids: Vec<i32> = seed_products(&server, 5)
+        .await
+        .iter()
+        .map(|p| p.id)
+        .collect();
+    let client = server.client();
+    server.clear_requests();
+    let query = Product::query()
+        .include(ids[0])
+        .include(ids[1])
+        .include(ids[3])
+        .exclude(ids[1])
+        .orderby(ProductSortBy::Id)
+        .order(SortOrder::Asc);
+    let found: Vec<i32> = client
+        .list::<Product>(query)
+        .await
+        .unwrap()
+        .iter()
+        .map(|p| p.id)
+        .collect();
+    assert_eq!(found, vec![ids[0], ids[3]]);
+    let request = server.requests().remove(0);
+    let include = format!("{},{},{}", ids[0], ids[1], ids[3]);
+    assert_eq!(request.param("include"), Some(include.as_str()));
+    assert_eq!(request.param("exclude"), Some(ids[1].to_string().as_str()));
+    assert_eq!(request.param("orderby"), Some("id"));
+    assert_eq!(request.param("order"), Some("asc"));
+
+    let created = server.entities::<Product>().unwrap()[0]
+        .date_created_gmt
+        .unwrap();
+    let since = |offset| Product::query().after(created + offset).dates_are_gmt();
+    let before = client.list::<Product>(since(-TimeDelta::hours(1)));
+    assert_eq!(before.await.unwrap().len(), 5);
+    let after = client.list::<Product>(since(TimeDelta::hours(1)));
+    assert!(after.await.unwrap().is_empty());
+
+    for (email, first_name) in [("ann@example.com", "Ann"), ("bob@example.com", "Bob")] {
+        let create = Customer::create(email).first_name(first_name);
+        client.create::<Customer>(create).await.unwrap();
+    }
+    let bob = client
+        .list::<Customer>(Customer::query().email("bob@example.com"))
+        .await
+        .unwrap();
+    assert_eq!(bob.len(), 1);
+    assert_eq!(bob[0].first_name, "Bob");
+    let ann = client
+        .list::<Customer>(Customer::query().search("ann"))
+        .await
+        .unwrap();
+    assert_eq!(ann.len(), 1);
+    assert_eq!(ann[0].email, "ann@example.com");
+
+    for code in ["SAVE10", "SAVE20"] {
+        let create = Coupon::create()
+            .code(code)
+            .discount_type(DiscountType::Percent)
+            .amount("10")
+            .build();
+        client.create::<Coupon>(create).await.unwrap();
+    }
+    let coupons = client
+        .list::<Coupon>(Coupon::query().code("SAVE20"))
+        .await
+        .unwrap();
+    assert_eq!(coupons.len(), 1);
+    assert_eq!(coupons[0].code, "SAVE20");
+
+    server.clear_requests();
+    let since = NaiveDate::from_ymd_opt(2024, 1, 1)
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .unwrap();
+    let query = Order::query()
+        .status(OrderStatus::Processing)
+        .customer(7)
+        .after(since)
+        .dates_are_gmt();
+    assert!(client.list::<Order>(query).await.unwrap().is_empty());
+    let request = server.requests().remove(0);
+    assert_eq!(request.path, "orders");
+    assert_eq!(request.param("status"), Some("processing"));
+    assert_eq!(request.param("customer"), Some("7"));
+    assert_eq!(request.param("after"), Some("2024-01-01T00:00:00"));
+    assert_eq!(request.param("dates_are_gmt"), Some("true"));
+}
+
+/// Keeps a copy of every request sent to the server, HTTPS requests are passed on over plain HTTP
+#[derive(Debug, Default)]
+struct Captured {
+    inner: reqwest::Client,
+    requests: Mutex<Vec<reqwest::Request>>,
+}
+impl Transport for Captured {
+    fn execute(&self, mut request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>> {
+        if let Some(copy) = request.try_clone() {
+            self.requests.lock().unwrap().push(copy);
+        }
+        if request.url().scheme() == "https" {
+            let _ = request.url_mut().set_scheme("http");
+        }
+        Transport::execute(&self.inner, request)
+    }
+}
+
+#[tokio::test]
+async fn namespaces() {
+    let server = MockServer::start().await.unwrap();
+    seed_products(&server, 1).await;
+    for (namespace, prefix) in [
+        (ApiNamespace::WcV3, "/wp-json/wc/v3/"),
+        (ApiNamespace::WcV2, "/wp-json/wc/v2/"),
+        (ApiNamespace::WcAnalytics, "/wp-json/wc-analytics/"),
+    ] {
+        let transport = Arc::new(Captured::default());
+        let client = server
+            .client_builder()
+            .namespace(namespace)
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        assert!(client.base_url().ends_with(prefix), "{}", client.base_url());
+        assert_eq!(client.list_all::<Product>().await.unwrap().len(), 1);
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests[0].url().path(), format!("{prefix}products/"));
+    }
+}
+
+#[tokio::test]
+async fn auto_auth() {
+    let server = MockServer::start().await.unwrap();
+    seed_products(&server, 1).await;
+    let sent = |transport: &Captured| {
+        let request = transport.requests.lock().unwrap().pop().unwrap();
+        let params: Vec<String> = request.url().query_pairs().map(|(k, _)| k.into()).collect();
+        let authorization = request
+            .headers()
+            .get(AUTHORIZATION)
+            .map(|v| v.to_str().unwrap().to_owned());
+        (params, authorization)
+    };
+
+    let transport = Arc::new(Captured::default());
+    let client = server
+        .client_builder()
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+    assert_eq!(client.auth(), Auth::Auto);
+    assert_eq!(client.list_all::<Product>().await.unwrap().len(), 1);
+    let (params, authorization) = sent(&transport);
+    assert!(params.contains(&String::from("oauth_signature")));
+    assert!(params.contains(&String::from("oauth_signature_method")));
+    assert_eq!(authorization, None);
+    let request = server.requests().pop().unwrap();
+    assert_eq!(request.param("oauth_signature_method"), Some("HMAC-SHA256"));
+
+    let transport = Arc::new(Captured::default());
+    let client = server
+        .client_builder()
+        .host(server.url().replacen("http://", "https://", 1))
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+    assert_eq!(client.list_all::<Product>().await.unwrap().len(), 1);
+    let (params, authorization) = sent(&transport);
+    assert!(
+        params.iter().all(|p| !p.starts_with("oauth_")),
+        "{params:?}"
+    );
+    assert!(
+        params.iter().all(|p| !p.starts_with("consumer_")),
+        "{params:?}"
+    );
+    assert!(authorization.is_some_and(|v| v.starts_with("Basic ")));
+}
+
+#[tokio::test]
+async fn oauth_signatures() {
+    let server = MockServer::start().await.unwrap();
+    server
+        .client()
+        .create::<Product>(Product::builder().name("Mug").sku("A B,C:D").build())
+        .await
+        .unwrap();
+    for method in [SignatureMethod::HmacSha1, SignatureMethod::HmacSha256] {
+        let client = server
+            .client_builder()
+            .auth(Auth::OAuth1(method))
+            .build()
+            .unwrap();
+        let found = client
+            .list::<Product>(Product::query().sku("A B,C:D").search("b,c:d"))
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1, "{method:?}");
+    }
+    let request = server.requests().pop().unwrap();
+    assert!(request.param("oauth_signature").is_some());
+    let client = server
+        .client_builder()
+        .credentials("ck_mock", "cs_wrong")
+        .build()
+        .unwrap();
+    assert!(matches!(
+        client.list_all::<Product>().await,
+        Err(Error::Unauthorized { .. })
+    ));
+}
+
+#[tokio::test]
+async fn strict_client() {
+    let server = MockServer::start().await.unwrap();
+    let product = seed_products(&server, 1).await.remove(0);
+    let mut json = server.json("products").remove(0);
+    json["_plugin_field"] = "yes".into();
+    server.insert_json("products", json);
+    let lenient = server.client();
+    assert_eq!(
+        lenient.retrieve::<Product>(product.id).await.unwrap().id,
+        product.id
+    );
+    let strict = server.client_builder().strict(true).build().unwrap();
+    match strict.retrieve::<Product>(product.id).await {
+        Err(Error::Deserialize { source, .. }) => {
+            assert!(source.to_string().contains("_plugin_field"), "{source}")
+        }
+        other => panic!("expected a deserialize error, got {other:?}"),
+    }
+    match strict.list_all::<Product>().await {
+        Err(Error::Pages(errors)) => {
+            assert!(matches!(errors[0].error, Error::Deserialize { .. }))
+        }
+        other => panic!("expected a page error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn crud_and_batch() {
+    let server = MockServer::start().await.unwrap();
+    let client = server.client();
+    let created: Product = client
+        .create(Product::builder().name("Mug").regular_price("9.99").build())
+        .await
+        .unwrap();
+    assert_eq!(created.name, "Mug");
+    assert!(created.id > 0);
+    let updated: Product = client
+        .update(created.id, Product::builder().name("Big mug").build())
+        .await
+        .unwrap();
+    assert_eq!(updated.name, "Big mug");
+    assert_eq!(updated.regular_price, created.regular_price);
+    let stored = server.entities::<Product>().unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].name, "Big mug");
+    let deleted: Product = client.delete(created.id).await.unwrap();
+    assert_eq!(deleted.id, created.id);
+    let missing = client.retrieve::<Product>(created.id).await.unwrap_err();
+    assert_eq!(missing.status().map(|s| s.as_u16()), Some(404));
+
+    let seeded = seed_products(&server, 3).await;
+    let renamed = client
+        .batch_update::<Product, _>(vec![Product::builder()
+            .id(seeded[0].id)
+            .name("Renamed")
+            .build()])
+        .await
+        .unwrap();
+    assert_eq!(renamed[0].name, "Renamed");
+    let removed = client
+        .batch_delete::<Product>(vec![seeded[1].id, seeded[2].id])
+        .await
+        .unwrap();
+    assert_eq!(removed.len(), 2);
+    assert_eq!(server.entities::<Product>().unwrap().len(), 1);
+
+    let customer: Customer = client
+        .create(
+            Customer::create("ann@example.com")
+                .first_name("Ann")
+                .billing_city("Oslo"),
+        )
+        .await
+        .unwrap();
+    assert_eq!(customer.billing.city, "Oslo");
+    assert_eq!(customer.email, "ann@example.com");
+    let coupon: Coupon = client
+        .create(
+            Coupon::create()
+                .code("SAVE10")
+                .discount_type(DiscountType::Percent)
+                .amount("10")
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        client.retrieve::<Coupon>(coupon.id).await.unwrap().code,
+        "SAVE10"
+    );
+}
+
+#[tokio::test]
+async fn typed_errors() {
+    let server = MockServer::start().await.unwrap();
+    let product = seed_products(&server, 1).await.remove(0);
+    let client = server
+        .client_builder()
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+    match client.retrieve::<Product>(product.id + 1).await {
+        Err(Error::NotFound(body)) => {
+            assert_eq!(body.code, "woocommerce_rest_invalid_id");
+            assert_eq!(body.data.and_then(|d| d.status), Some(404));
+        }
+        other => panic!("expected not found, got {other:?}"),
+    }
+
+    server.inject(Fault::status(500).path("products"));
+    match client.retrieve::<Product>(product.id).await {
+        Err(Error::Status { status, body }) => {
+            assert_eq!(status.as_u16(), 500);
+            assert_eq!(body.code, "mock_injected_fault");
+            assert_eq!(body.message, "Injected fault");
+        }
+        other => panic!("expected a status error, got {other:?}"),
+    }
+
+    let mut json = server.json("products").remove(0);
+    json["dimensions"] = "10x10x10".into();
+    server.insert_json("products", json);
+    match client.retrieve::<Product>(product.id).await {
+        Err(Error::Deserialize { payload, .. }) => {
+            assert!(payload.contains("10x10x10"), "{payload}")
+        }
+        other => panic!("expected a deserialize error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn failed_batch_chunk() {
+    let server = MockServer::start().await.unwrap();
+    let products = (1..=250)
+        .map(|i| Product::builder().name(format!("Product {i}")).build())
+        .collect::<Vec<_>>();
+    server.inject(Fault::status(500).path("products/batch"));
+    let error = server
+        .client()
+        .batch_create::<Product, _>(products)
+        .await
+        .unwrap_err();
+    let Error::Pages(errors) = error else {
+        panic!("{error}");
+    };
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error.status().map(|s| s.as_u16()), Some(500));
+    assert_eq!(server.entities::<Product>().unwrap().len(), 150);
+}
+
+#[tokio::test]
+async fn injected_faults() {
+    let server = MockServer::start().await.unwrap();
+    seed_products(&server, 2).await;
+    let client = server
+        .client_builder()
+        .retry_policy(fast_retries())
+        .build()
+        .unwrap();
+
+    server.inject(Fault::status(503).path("products").times(2));
+    assert_eq!(client.list_all::<Product>().await.unwrap().len(), 2);
+
+    server.inject(Fault::status(500).path("products").times(3));
+    let error = client.list_all::<Product>().await.unwrap_err();
+    assert_eq!(error.status().map(|s| s.as_u16()), Some(500));
+
+    server.inject(Fault::status(429).retry_after(0));
+    assert_eq!(client.list_all::<Product>().await.unwrap().len(), 2);
+
+    server.inject(Fault::status(503).method(reqwest::Method::POST));
+    let create = Product::builder().name("Lamp").build();
+    assert!(client.create::<Product>(create).await.is_err());
+    assert_eq!(server.entities::<Product>().unwrap().len(), 2);
+
+    server.inject(
+        Fault::status(429)
+            .method(reqwest::Method::POST)
+            .retry_after(0),
+    );
+    let create = Product::builder().name("Lamp").build();
+    assert!(client.create::<Product>(create).await.is_ok());
+    assert_eq!(server.entities::<Product>().unwrap().len(), 3);
+
+    server.inject(Fault::status(401));
+    assert!(matches!(
+        client.list_all::<Product>().await,
+        Err(Error::Unauthorized { .. })
+    ));
+}
+
+/// Tracks the largest number of requests in flight at the same time
+#[derive(Debug, Default)]
+struct InFlight {
+    inner: reqwest::Client,
+    current: AtomicUsize,
+    max: AtomicUsize,
+}
+impl Transport for InFlight {
+    fn execute(&self, request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>> {
+        Box::pin(async move {
+            let current = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max.fetch_max(current, Ordering::SeqCst);
+            let response = Transport::execute(&self.inner, request).await;
+            self.current.fetch_sub(1, Ordering::SeqCst);
+            response
+        })
+    }
+}
+
+#[tokio::test]
+async fn request_limits() {
+    let server = MockServer::start().await.unwrap();
+    seed_products(&server, 250).await;
+    server.set_latency(Duration::from_millis(20));
+
+    let transport = Arc::new(InFlight::default());
+    let client = server
+        .client_builder()
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+    client.list_all::<Product>().await.unwrap();
+    assert!(transport.max.load(Ordering::SeqCst) > 2);
+
+    // Both clones share the limit, so their listings together stay at two requests
+    let transport = Arc::new(InFlight::default());
+    let client = server
+        .client_builder()
+        .transport(transport.clone())
+        .concurrency_limit(2)
+        .build()
+        .unwrap();
+    let other = client.clone();
+    let (a, b) = tokio::join!(client.list_all::<Product>(), other.list_all::<Product>());
+    assert_eq!(a.unwrap().len() + b.unwrap().len(), 500);
+    assert_eq!(transport.max.load(Ordering::SeqCst), 2);
+
+    server.set_latency(Duration::ZERO);
+    let client = server.client_builder().rate_limit(20).build().unwrap();
+    let started = Instant::now();
+    client.list_all::<Product>().await.unwrap();
+    // Five pages spread 50ms apart
+    assert!(started.elapsed() >= Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn latency() {
+    let server = MockServer::start().await.unwrap();
+    server.set_latency(Duration::from_millis(100));
+    let started = Instant::now();
+    server.client().list_all::<Product>().await.unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(100));
+    let client = server
+        .client_builder()
+        .timeout(Duration::from_millis(20))
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+    assert!(matches!(
+        client.list_all::<Product>().await,
+        Err(Error::Transport(_))
+    ));
+}
+
+#[tokio::test]
+async fn incremental_sync() {
+    let server = MockServer::start().await.unwrap();
+    let seeded = seed_products(&server, 3).await;
+    let engine = SyncEngine::new(server.client(), Arc::new(MemoryCheckpointStore::new()))
+        .reconcile_every(Duration::ZERO);
+
+    let batch = engine.sync::<Product>().await.unwrap();
+    assert_eq!(batch.changes.len(), 3);
+    assert!(batch
+        .changes
+        .iter()
+        .all(|c| matches!(c, Change::Created(_))));
+    engine.commit(&batch).await.unwrap();
+    assert!(engine.sync::<Product>().await.unwrap().is_empty());
+
+    // Move the edit a second ahead, the mock stores dates with one second resolution
+    let mut edited = seeded[1].clone();
+    edited.name = String::from("Edited");
+    edited.date_modified = edited
+        .date_modified
+        .map(|date| date + TimeDelta::seconds(1));
+    edited.date_modified_gmt = edited
+        .date_modified_gmt
+        .map(|date| date + TimeDelta::seconds(1));
+    server.insert(&edited).unwrap();
+    server
+        .client()
+        .delete::<Product>(seeded[2].id)
+        .await
+        .unwrap();
+
+    let batch = engine.sync::<Product>().await.unwrap();
+    let ids: Vec<(bool, i32)> = batch
+        .changes
+        .iter()
+        .map(|c| (matches!(c, Change::Deleted(_)), c.id()))
+        .collect();
+    assert_eq!(ids, vec![(false, seeded[1].id), (true, seeded[2].id)]);
+    engine.commit(&batch).await.unwrap();
+    assert_eq!(batch.checkpoint().ids.len(), 2);
+}
+
+/// Edits a product on the server right before the second page of a listing is requested
+#[derive(Debug)]
+struct EditBeforePage2 {
+    server: Arc<MockServer>,
+    edit: Mutex<Option<Product>>,
+    inner: reqwest::Client,
+}
+impl Transport for EditBeforePage2 {
+    fn execute(&self, request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>> {
+        let page2 = request
+            .url()
+            .query_pairs()
+            .any(|(k, v)| k == "page" && v == "2");
+        if let Some(edit) = page2.then(|| self.edit.lock().unwrap().take()).flatten() {
+            self.server.insert(&edit).unwrap();
+        }
+        Transport::execute(&self.inner, request)
+    }
+}
+
+#[tokio::test]
+async fn sync_with_edit_between_pages() {
+    let server = Arc::new(MockServer::start().await.unwrap());
+    let seeded = seed_products(&server, 3).await;
+    let mut edited = seeded[0].clone();
+    edited.name = String::from("Edited");
+    edited.date_modified = seeded[2]
+        .date_modified
+        .map(|date| date + TimeDelta::seconds(1));
+    edited.date_modified_gmt = seeded[2]
+        .date_modified_gmt
+        .map(|date| date + TimeDelta::seconds(1));
+    let transport = EditBeforePage2 {
+        server: server.clone(),
+        edit: Mutex::new(Some(edited)),
+        inner: reqwest::Client::new(),
+    };
+    let client = server
+        .client_builder()
+        .transport(transport)
+        .build()
+        .unwrap();
+    let engine = SyncEngine::new(client, Arc::new(MemoryCheckpointStore::new()))
+        .stream_options(StreamOptions::default().per_page(1));
+
+    // The edit moves the first product to the end, the second one shifts onto the first page
+    let batch = engine.sync::<Product>().await.unwrap();
+    let ids: Vec<i32> = batch.changes.iter().map(Change::id).collect();
+    assert_eq!(ids, vec![seeded[0].id, seeded[2].id, seeded[0].id]);
+    engine.commit(&batch).await.unwrap();
+
+    let batch = engine.sync::<Product>().await.unwrap();
+    let ids: Vec<i32> = batch.changes.iter().map(Change::id).collect();
+    assert_eq!(ids, vec![seeded[1].id]);
+    assert!(matches!(batch.changes[0], Change::Created(_)));
+    engine.commit(&batch).await.unwrap();
+    assert!(engine.sync::<Product>().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn response_cache() {
+    let server = MockServer::start().await.unwrap();
+    let seeded = seed_products(&server, 1).await;
+    let client = server
+        .client_builder()
+        .cache(ResponseCache::memory().ttl::<Product>(Duration::from_secs(60)))
+        .build()
+        .unwrap();
+    server.clear_requests();
+    for _ in 0..3 {
+        client.retrieve::<Product>(seeded[0].id).await.unwrap();
+    }
+    assert_eq!(server.requests().len(), 1);
+
+    let update = Product::builder().name("Changed").build();
+    client
+        .update::<Product>(seeded[0].id, update)
+        .await
+        .unwrap();
+    let retrieved = client.retrieve::<Product>(seeded[0].id).await.unwrap();
+    assert_eq!(retrieved.name, "Changed");
+    assert_eq!(server.requests().len(), 3);
+
+    // Resources below the products endpoint are not products
+    server.clear_requests();
+    for _ in 0..2 {
+        client.list_all::<Product>().await.unwrap();
+        client.list_all::<Category>().await.unwrap();
+        client
+            .list_all_subentities::<ProductVariation>(seeded[0].id)
+            .await
+            .unwrap();
+    }
+    let variations = format!("products/{}/variations", seeded[0].id);
+    let paths: Vec<String> = server.requests().into_iter().map(|r| r.path).collect();
+    assert_eq!(
+        paths,
+        [
+            "products",
+            "products/categories",
+            &variations,
+            "products/categories",
+            &variations
+        ]
+    );
+
+    let client = server
+        .client_builder()
+        .cache(ResponseCache::memory().ttl::<ProductVariation>(Duration::from_secs(60)))
+        .build()
+        .unwrap();
+    server.clear_requests();
+    for _ in 0..2 {
+        client
+            .list_all_subentities::<ProductVariation>(seeded[0].id)
+            .await
+            .unwrap();
+        client.retrieve::<Product>(seeded[0].id).await.unwrap();
+    }
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn record_and_replay() {
+    let server = MockServer::start().await.unwrap();
+    seed_products(&server, 3).await;
+    let path = std::env::temp_dir().join(format!("woo-fixture-{}.json", std::process::id()));
+    let recorder = Arc::new(RecordingTransport::new(&path).redact(server.url()));
+    for auth in [Auth::QueryString, Auth::OAuth1(SignatureMethod::HmacSha256)] {
+        let client = server
+            .client_builder()
+            .auth(auth)
+            .transport(recorder.clone())
+            .build()
+            .unwrap();
+        client.list_all::<Product>().await.unwrap();
+    }
+    let client = server
+        .client_builder()
+        .transport(recorder.clone())
+        .build()
+        .unwrap();
+    let created: Product = client
+        .create(Product::builder().name("Recorded").build())
+        .await
+        .unwrap();
+    assert_eq!(recorder.interactions().len(), 3);
+
+    let fixture = std::fs::read_to_string(&path).unwrap();
+    let host = server.url();
+    for secret in [
+        "ck_mock",
+        "cs_mock",
+        "oauth_",
+        "Authorization",
+        host.as_str(),
+    ] {
+        assert!(!fixture.contains(secret), "{secret} was recorded");
+    }
+    let replay = Arc::new(ReplayTransport::load(&path).unwrap());
+    let client = ApiClient::builder()
+        .host("replay.invalid")
+        .credentials("ck", "cs")
+        .transport(replay.clone())
+        .build()
+        .unwrap();
+    assert_eq!(client.list_all::<Product>().await.unwrap().len(), 3);
+    assert_eq!(client.list_all::<Product>().await.unwrap().len(), 3);
+    let replayed: Product = client
+        .create(Product::builder().name("Recorded").build())
+        .await
+        .unwrap();
+    assert_eq!(replayed.id, created.id);
+    assert!(replay.unused().is_empty());
+    assert!(matches!(
+        client.list_all::<Product>().await,
+        Err(Error::Fixture(_))
+    ));
+    std::fs::remove_file(path).unwrap();
+}
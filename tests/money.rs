@@ -0,0 +1,107 @@
+//! Checks of the exact Money type and of models with missing amounts.
+
+use rust_woocommerce::{
+    strict, Country, Coupon, CurrencyISO, Money, Order, OrderLineItemProperties, Product,
+    ProductVariation,
+};
+use serde::Serialize;
+
+mod common;
+use common::fixture_entity;
+
+fn json(value: &impl Serialize) -> serde_json::Value {
+    serde_json::to_value(value).unwrap()
+}
+
+#[test]
+fn amounts() {
+    let price: Money = serde_json::from_value(serde_json::json!("19.99")).unwrap();
+    let quantity_price = price * 3;
+    assert_eq!(quantity_price, "59.97".parse().unwrap());
+    let empty: Money = serde_json::from_value(serde_json::json!("")).unwrap();
+    assert!(empty.is_zero());
+    assert!(strict::from_value::<Money>(serde_json::json!("")).is_err());
+    let total: Money = [price, quantity_price, empty].iter().sum();
+    assert_eq!(json(&total), "79.96");
+    assert_eq!(json(&Money::new(1050, 2)), "10.50");
+    let line: OrderLineItemProperties = serde_json::from_value(serde_json::json!({
+        "id": 1, "name": "Mug", "product_id": 2, "variation_id": 0, "quantity": 3, "tax_class": "",
+        "subtotal": "30.00", "subtotal_tax": "0.00", "total": "30.00", "total_tax": "0.00",
+        "taxes": [], "meta_data": [], "sku": null, "price": 10.1
+    }))
+    .unwrap();
+    assert_eq!(line.price * line.quantity, "30.3".parse().unwrap());
+    let germany: Country = serde_json::from_value(serde_json::json!({
+        "code": "DE", "currency_code": "EUR", "currency_pos": "right_space", "decimal_sep": ",",
+        "dimension_unit": "cm", "name": "Germany", "num_decimals": 2, "states": [],
+        "thousand_sep": ".", "weight_unit": "kg"
+    }))
+    .unwrap();
+    let amount: Money = "-1234567.455".parse().unwrap();
+    assert_eq!(
+        amount.format(&CurrencyISO::EUR, &germany),
+        "-1.234.567,46 EUR"
+    );
+    assert_eq!(Money::new(5, 1).format_with_symbol("€", &germany), "0,50 €");
+}
+
+#[test]
+fn missing_money_fields() {
+    let totals = [
+        "discount_total",
+        "discount_tax",
+        "shipping_total",
+        "shipping_tax",
+        "cart_tax",
+        "total",
+        "total_tax",
+    ];
+    let order: Order = serde_json::from_value(fixture_entity("orders", &totals)).unwrap();
+    assert!(order.total.is_zero());
+    let amounts = ["amount", "minimum_amount", "maximum_amount"];
+    let coupon: Coupon = serde_json::from_value(fixture_entity("coupons", &amounts)).unwrap();
+    assert!(coupon.amount.is_zero());
+    let prices = ["price", "regular_price"];
+    let product: Product = serde_json::from_value(fixture_entity("products", &prices)).unwrap();
+    assert_eq!(product.price, None);
+    assert_eq!(product.regular_price, None);
+    let mut variation = fixture_entity("products", &prices);
+    let fields = variation.as_object_mut().unwrap();
+    for field in [
+        "name",
+        "slug",
+        "type",
+        "featured",
+        "catalog_visibility",
+        "short_description",
+        "price_html",
+        "total_sales",
+        "external_url",
+        "button_text",
+        "sold_individually",
+        "shipping_required",
+        "shipping_taxable",
+        "reviews_allowed",
+        "average_rating",
+        "rating_count",
+        "related_ids",
+        "upsell_ids",
+        "cross_sell_ids",
+        "parent_id",
+        "purchase_note",
+        "categories",
+        "tags",
+        "images",
+        "default_attributes",
+        "variations",
+        "grouped_products",
+    ] {
+        fields.remove(field);
+    }
+    fields.insert(String::from("image"), serde_json::Value::Null);
+    fields.insert(String::from("attributes"), serde_json::json!([]));
+    fields.insert(String::from("price"), "".into());
+    let variation: ProductVariation = serde_json::from_value(variation).unwrap();
+    assert_eq!(variation.price, None);
+    assert_eq!(variation.regular_price, None);
+}
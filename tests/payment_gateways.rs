@@ -0,0 +1,49 @@
+//! Checks of the payment gateway model against what WooCommerce and gateway plugins send.
+
+use rust_woocommerce::payment_gateways::{PaymentGateway, SettingType};
+use serde::Serialize;
+use serde_json::json;
+
+fn json(value: &impl Serialize) -> serde_json::Value {
+    serde_json::to_value(value).unwrap()
+}
+
+#[test]
+fn settings() {
+    let gateway: PaymentGateway = serde_json::from_value(json!({
+        "id": "stripe",
+        "title": "Credit Card (Stripe)",
+        "description": "Pay with your credit card via Stripe.",
+        "order": 1,
+        "enabled": true,
+        "method_title": "Stripe",
+        "method_description": "Stripe works by adding payment fields on the checkout.",
+        "method_supports": ["products", "refunds"],
+        "settings": {
+            "testmode": {"id": "testmode", "label": "Enable Test Mode", "description": "", "type": "checkbox", "value": "yes", "default": "yes", "tip": "Use test keys", "placeholder": ""},
+            "api_credentials": {"id": "api_credentials", "label": "Stripe Account Keys", "type": "stripe_account_keys", "value": "", "default": "", "tip": "", "placeholder": ""},
+            "payment_request_button_locations": {"id": "payment_request_button_locations", "label": "Locations", "type": "multiselect", "value": ["product", "cart"], "default": [], "tip": "", "placeholder": "", "options": {"product": "Product", "cart": "Cart"}}
+        }
+    }))
+    .unwrap();
+    assert_eq!(gateway.order, "1");
+    assert_eq!(
+        gateway.settings["testmode"].tip.as_deref(),
+        Some("Use test keys")
+    );
+    assert_eq!(gateway.settings["api_credentials"].tip, None);
+    assert_eq!(
+        gateway.settings["api_credentials"].setting_type,
+        SettingType::Other(String::from("stripe_account_keys"))
+    );
+    assert_eq!(
+        json(&gateway.settings["api_credentials"])["type"],
+        "stripe_account_keys"
+    );
+    let cod: PaymentGateway = serde_json::from_value(json!({
+        "id": "cod", "title": "", "description": "", "order": "", "enabled": false,
+        "method_title": "Cash on delivery", "method_description": "", "method_supports": [], "settings": []
+    }))
+    .unwrap();
+    assert!(cod.settings.is_empty());
+}
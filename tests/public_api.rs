@@ -0,0 +1,391 @@
+//! Compile-time checks that every resource, its builders and its typestate markers are reachable from outside the crate.
+
+use rust_woocommerce::{
+    coupons, customers, data, order_notes, orders, payment_gateways, product_attribute_terms,
+    product_attributes, product_categories, product_reviews, product_shipping_classes,
+    product_tags, product_variations, products, refunds, reports, settings, shipping_methods,
+    shipping_zone_locations, shipping_zone_methods, shipping_zones, store, sync, tax_classes,
+    tax_rates, webhooks, ApiClient, BatchObject, Entity,
+};
+use serde::Serialize;
+
+fn json(value: &impl Serialize) -> serde_json::Value {
+    serde_json::to_value(value).unwrap()
+}
+
+#[test]
+fn client_builder() {
+    let client = ApiClient::builder()
+        .host("shop.example.com")
+        .credentials("ck", "cs")
+        .namespace(rust_woocommerce::ApiNamespace::WcV2)
+        .auth(rust_woocommerce::Auth::OAuth1(
+            rust_woocommerce::SignatureMethod::HmacSha1,
+        ))
+        .retry_policy(rust_woocommerce::RetryPolicy::none())
+        .build()
+        .unwrap();
+    assert_eq!(client.base_url(), "https://shop.example.com/wp-json/wc/v2/");
+}
+
+#[test]
+fn coupons() {
+    let create: coupons::CreateCoupon = coupons::Coupon::create()
+        .code("SALE")
+        .discount_type(coupons::DiscountType::Percent)
+        .amount("10")
+        .build();
+    assert_eq!(json(&create)["code"], "SALE");
+    let update: coupons::UpdateCoupon = coupons::Coupon::update().id(1).amount("5").build();
+    assert_eq!(json(&update)["amount"], "5");
+    let _: coupons::CreateCouponBuilder<
+        coupons::NoCode,
+        coupons::NoDiscountType,
+        coupons::NoAmount,
+    > = coupons::CreateCoupon::builder();
+    assert_eq!(coupons::Coupon::endpoint(), "coupons/");
+}
+
+#[test]
+fn customers() {
+    let create = customers::CreateCustomer::new("john@example.com").first_name("John");
+    assert_eq!(json(&create)["email"], "john@example.com");
+    let update: customers::UpdateCustomer = customers::Customer::update().id(1);
+    assert_eq!(json(&update)["id"], 1);
+    assert_eq!(customers::Customer::endpoint(), "customers/");
+}
+
+#[test]
+fn data() {
+    assert_eq!(data::Data::endpoint(), "data/");
+}
+
+#[test]
+fn order_notes() {
+    let create: order_notes::OrderNotesCreate =
+        order_notes::OrderNotes::create().note("Packed").build();
+    assert_eq!(json(&create)["note"], "Packed");
+    let update: order_notes::OrderNotesUpdate = order_notes::OrderNotes::update()
+        .id(1)
+        .note("Shipped")
+        .build();
+    assert_eq!(json(&update)["note"], "Shipped");
+    let _: order_notes::OrderNotesCreateBuilder<order_notes::NoNote> =
+        order_notes::OrderNotes::create();
+    assert_eq!(
+        order_notes::OrderNotes::child_endpoint(7),
+        "orders/7/notes/"
+    );
+}
+
+#[test]
+fn orders() {
+    assert!(orders::Order::create().build().is_err());
+    let create: orders::CreateOrder = orders::Order::create()
+        .billing_email("john@example.com")
+        .build()
+        .unwrap();
+    assert_eq!(json(&create)["billing"]["email"], "john@example.com");
+    let update: orders::UpdateOrder = orders::Order::update().build();
+    let _ = json(&update);
+    let _: orders::OrderQuery = orders::Order::query();
+    assert_eq!(orders::Order::endpoint(), "orders/");
+}
+
+#[test]
+fn payment_gateways() {
+    let update: payment_gateways::PaymentGatewayUpdate =
+        payment_gateways::PaymentGateway::turn_on();
+    assert_eq!(json(&update)["enabled"], true);
+    let update = payment_gateways::PaymentGateway::update()
+        .title("Card")
+        .order(2)
+        .setting("testmode", "yes")
+        .setting("payment_request_button_locations", vec!["product", "cart"])
+        .build();
+    assert_eq!(
+        json(&update),
+        serde_json::json!({
+            "title": "Card",
+            "order": 2,
+            "settings": {
+                "payment_request_button_locations": ["product", "cart"],
+                "testmode": "yes"
+            }
+        })
+    );
+    assert_eq!(
+        payment_gateways::PaymentGateway::endpoint(),
+        "payment_gateways/"
+    );
+}
+
+#[test]
+fn string_ids() {
+    let client = ApiClient::init("shop.example.com", "ck", "cs").unwrap();
+    // Futures are only built to check the signatures accept slugs, nothing is sent
+    drop(client.retrieve::<payment_gateways::PaymentGateway>("bacs"));
+    drop(client.update::<payment_gateways::PaymentGateway>(
+        String::from("bacs"),
+        payment_gateways::PaymentGateway::turn_off(),
+    ));
+    drop(client.retrieve::<settings::SettingGroup>("general"));
+    drop(client.delete::<tax_classes::TaxClass>("zero-rate"));
+    drop(client.retrieve::<products::Product>(42));
+}
+
+#[test]
+fn product_attribute_terms() {
+    let create: product_attribute_terms::AttributeTermCreate =
+        product_attribute_terms::AttributeTerm::create()
+            .name("Red")
+            .build();
+    assert_eq!(json(&create)["name"], "Red");
+    let update: product_attribute_terms::AttributeTermUpdate =
+        product_attribute_terms::AttributeTerm::update()
+            .id(1)
+            .name("Blue")
+            .build();
+    assert_eq!(json(&update)["name"], "Blue");
+    let _: product_attribute_terms::AttributeTermCreateBuilder<product_attribute_terms::NoName> =
+        product_attribute_terms::AttributeTerm::create();
+    assert_eq!(
+        product_attribute_terms::AttributeTerm::child_endpoint(3),
+        "products/attributes/3/terms/"
+    );
+}
+
+#[test]
+fn product_attributes() {
+    let create: product_attributes::AttributeCreate = product_attributes::Attribute::create()
+        .name("Color")
+        .build();
+    assert_eq!(json(&create)["name"], "Color");
+    let update: product_attributes::AttributeUpdate = product_attributes::Attribute::update()
+        .id(1)
+        .name("Size")
+        .build();
+    assert_eq!(json(&update)["name"], "Size");
+    assert_eq!(
+        product_attributes::Attribute::endpoint(),
+        "products/attributes/"
+    );
+}
+
+#[test]
+fn product_categories() {
+    let create = product_categories::CategoryCreate::new("Shoes").slug("shoes");
+    assert_eq!(json(&create)["name"], "Shoes");
+    let update: product_categories::CategoryUpdate =
+        product_categories::Category::update().id(1).name("Boots");
+    assert_eq!(json(&update)["name"], "Boots");
+    assert_eq!(
+        product_categories::Category::endpoint(),
+        "products/categories/"
+    );
+}
+
+#[test]
+fn product_reviews() {
+    let create: product_reviews::ProductReviewCreate = product_reviews::ProductReview::create()
+        .product_id(1)
+        .reviewer_email("jane@example.com")
+        .rating(7)
+        .build();
+    assert_eq!(json(&create)["rating"], 5);
+    let update: product_reviews::ProductReviewUpdate = product_reviews::ProductReview::update()
+        .id(1)
+        .rating(-1)
+        .build();
+    assert_eq!(json(&update)["rating"], 0);
+    let _: product_reviews::ProductReviewCreateBuilder<
+        product_reviews::NoId,
+        product_reviews::NoEmail,
+    > = product_reviews::ProductReview::create();
+    assert_eq!(
+        product_reviews::ProductReview::endpoint(),
+        "products/reviews/"
+    );
+}
+
+#[test]
+fn product_shipping_classes() {
+    let create: product_shipping_classes::ShippingClassCreate =
+        product_shipping_classes::ShippingClass::create()
+            .name("Heavy")
+            .build();
+    assert_eq!(json(&create)["name"], "Heavy");
+    let update: product_shipping_classes::ShippingClassUpdate =
+        product_shipping_classes::ShippingClass::update()
+            .id(1)
+            .name("Light")
+            .build();
+    assert_eq!(json(&update)["name"], "Light");
+    assert_eq!(
+        product_shipping_classes::ShippingClass::endpoint(),
+        "products/shipping_classes/"
+    );
+}
+
+#[test]
+fn product_tags() {
+    let create: product_tags::ProductTagCreate =
+        product_tags::ProductTag::create().name("New").build();
+    assert_eq!(json(&create)["name"], "New");
+    let update: product_tags::ProductTagUpdate =
+        product_tags::ProductTag::update().id(1).name("Old").build();
+    assert_eq!(json(&update)["name"], "Old");
+    assert_eq!(product_tags::ProductTag::endpoint(), "products/tags/");
+}
+
+#[test]
+fn product_variations() {
+    let modify: product_variations::ProductVariationModify =
+        product_variations::ProductVariation::builder().build();
+    let _ = json(&modify);
+    assert_eq!(
+        product_variations::ProductVariation::child_endpoint(5),
+        "products/5/variations/"
+    );
+}
+
+#[test]
+fn products() {
+    let modify: products::ProductModify = products::Product::builder().name("Shirt").build();
+    assert_eq!(json(&modify)["name"], "Shirt");
+    let _: products::ProductQuery = products::Product::query();
+    let _: products::ProductTag = products::ProductTag {
+        id: 1,
+        name: String::from("New"),
+        slug: String::from("new"),
+    };
+    let batch = BatchObject::builder().add_create(modify).build();
+    assert!(json(&batch)["create"].is_array());
+    assert_eq!(products::Product::endpoint(), "products/");
+}
+
+#[test]
+fn refunds() {
+    let create: refunds::RefundCreate = refunds::Refund::create().amount("10").build();
+    assert_eq!(json(&create)["amount"], "10");
+    let item: refunds::OrderRefundLineItemCreate = refunds::OrderRefundLineItemCreate::builder()
+        .id(1)
+        .quantity(2)
+        .build();
+    let _ = json(&item);
+    let _: refunds::RefundCreateBuilder<refunds::NoAmount, refunds::NoItems> =
+        refunds::Refund::create();
+    assert_eq!(refunds::Refund::child_endpoint(2), "orders/2/refunds/");
+}
+
+#[test]
+fn reports() {
+    assert_eq!(reports::Report::endpoint(), "reports/");
+    assert_eq!(reports::SaleReport::endpoint(), "reports/sales/");
+    assert_eq!(
+        reports::TopSellersReport::endpoint(),
+        "reports/top_sellers/"
+    );
+    assert_eq!(
+        reports::ReportOrdersTotals::endpoint(),
+        "reports/orders/totals/"
+    );
+}
+
+#[test]
+fn settings() {
+    let update = settings::SettingOptionUpdate {
+        value: serde_json::json!("yes"),
+    };
+    assert_eq!(json(&update)["value"], "yes");
+    assert_eq!(settings::SettingGroup::endpoint(), "settings/");
+    let _: Option<settings::SettingsDiff> = None;
+}
+
+#[test]
+fn shipping() {
+    assert_eq!(
+        shipping_methods::ShippingMethod::endpoint(),
+        "shipping_methods/"
+    );
+    assert_eq!(
+        shipping_zone_locations::ShippingZoneLocation::child_endpoint(1),
+        "shipping/zones/1/locations/"
+    );
+    assert_eq!(
+        shipping_zone_methods::ShippingZoneMethod::child_endpoint(1),
+        "shipping/zones/1/methods/"
+    );
+    let create = shipping_zones::ShippingZoneCreate::new("Europe").order(1);
+    assert_eq!(json(&create)["name"], "Europe");
+    let update: shipping_zones::ShippingZoneUpdate =
+        shipping_zones::ShippingZone::update().id(1).name("EU");
+    assert_eq!(json(&update)["name"], "EU");
+    assert_eq!(shipping_zones::ShippingZone::endpoint(), "shipping/zones/");
+    let include: shipping_zone_methods::ShippingZoneMethodCreate =
+        shipping_zone_methods::ShippingZoneMethod::include("flat_rate")
+            .title("Standard")
+            .setting("cost", "5");
+    assert_eq!(json(&include)["settings"]["cost"], "5");
+    let update: shipping_zone_methods::ShippingZoneMethodUpdate =
+        shipping_zone_methods::ShippingZoneMethod::update().enabled(false);
+    assert_eq!(json(&update), serde_json::json!({"enabled": false}));
+    let location = shipping_zone_locations::ShippingZoneLocation::state("US:CA");
+    assert_eq!(
+        json(&location),
+        serde_json::json!({"code": "US:CA", "type": "state"})
+    );
+    let spec = shipping_zones::ShippingZoneSpec::new("California")
+        .location(location)
+        .method(shipping_zones::ShippingMethodSpec::flat_rate("5").title("Standard"))
+        .method(shipping_zones::ShippingMethodSpec::free_shipping().disabled());
+    assert_eq!(spec.methods.len(), 2);
+    let _: Option<shipping_zones::ShippingZonePlan> = None;
+}
+
+#[test]
+fn store() {
+    let _: store::StoreConfig = store::StoreConfig::default();
+    let _: store::StorePlan = store::StorePlan::default();
+}
+
+#[test]
+fn sync() {
+    let _: sync::Checkpoint = sync::Checkpoint::default();
+    let _: sync::MemoryCheckpointStore = sync::MemoryCheckpointStore::new();
+}
+
+#[test]
+fn taxes() {
+    let create: tax_classes::TaxClassCreate = tax_classes::TaxClass::create().name("Zero").build();
+    assert_eq!(json(&create)["name"], "Zero");
+    let create: tax_rates::TaxRateCreate = tax_rates::TaxRate::create().rate("20").build();
+    assert_eq!(json(&create)["rate"], "20");
+    let update: tax_rates::TaxRateUpdate = tax_rates::TaxRate::update().id(1).rate("10").build();
+    assert_eq!(json(&update)["rate"], "10");
+    assert_eq!(tax_classes::TaxClass::endpoint(), "taxes/classes/");
+    assert_eq!(tax_rates::TaxRate::endpoint(), "taxes/");
+}
+
+#[test]
+fn webhooks() {
+    let create: webhooks::WebhookCreate = webhooks::Webhook::create()
+        .resource(webhooks::Resource::Order)
+        .event(webhooks::Event::Created)
+        .delivery_url("https://example.com/hook")
+        .build();
+    assert_eq!(json(&create)["topic"], "order.created");
+    let update: webhooks::WebhookUpdate = webhooks::Webhook::update()
+        .id(1)
+        .name("Orders")
+        .build()
+        .unwrap();
+    assert_eq!(json(&update)["name"], "Orders");
+    let _: webhooks::WebhookCreateBuilder<
+        webhooks::NoResource,
+        webhooks::NoEvent,
+        webhooks::NoUrl,
+    > = webhooks::Webhook::create();
+    assert_eq!(webhooks::Webhook::endpoint(), "webhooks/");
+    let _: webhooks::WebhookVerifier = webhooks::WebhookVerifier::new("secret");
+}
@@ -0,0 +1,78 @@
+//! Checks of the retry policy against replayed and unreachable stores.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{TimeDelta, Utc};
+use futures::future::BoxFuture;
+use rust_woocommerce::{
+    ApiClient, Entity, Error, Interaction, ReplayTransport, Result, RetryPolicy, TaxClass,
+    Transport,
+};
+
+/// Counts the requests passed on to a reqwest client
+#[derive(Debug, Default)]
+struct CountingTransport {
+    inner: reqwest::Client,
+    calls: AtomicUsize,
+}
+impl Transport for CountingTransport {
+    fn execute(&self, request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Transport::execute(&self.inner, request)
+    }
+}
+
+#[tokio::test]
+async fn retry_after_date() {
+    let retry_at = Utc::now() + TimeDelta::hours(1);
+    let rate_limited = Interaction {
+        method: String::from("GET"),
+        path: format!("/wp-json/wc/v3/{}?page=1&per_page=50", TaxClass::endpoint()),
+        request_body: None,
+        status: 429,
+        headers: vec![(String::from("Retry-After"), retry_at.to_rfc2822())],
+        body: serde_json::json!({"code": "too_many_requests", "message": "Slow down", "data": null}),
+    };
+    let client = ApiClient::builder()
+        .host("replay.invalid")
+        .credentials("ck", "cs")
+        .retry_policy(RetryPolicy::none())
+        .transport(ReplayTransport::new(vec![rate_limited]))
+        .build()
+        .unwrap();
+    let error = client.list_all::<TaxClass>().await.unwrap_err();
+    let Error::RateLimited {
+        retry_after: Some(retry_after),
+        ..
+    } = error
+    else {
+        panic!("{error}");
+    };
+    assert!(
+        (3590..=3600).contains(&retry_after.as_secs()),
+        "{retry_after:?}"
+    );
+}
+
+#[tokio::test]
+async fn connection_failures() {
+    // Nothing listens on port 1, the store never saw the POST so it is retried
+    let transport = Arc::new(CountingTransport::default());
+    let client = ApiClient::builder()
+        .host("http://127.0.0.1:1")
+        .credentials("ck", "cs")
+        .retry_policy(
+            RetryPolicy::default()
+                .max_attempts(3)
+                .base_delay(Duration::from_millis(1)),
+        )
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+    let create = TaxClass::create().name("Reduced rate").build();
+    let error = client.create::<TaxClass>(create).await.unwrap_err();
+    assert!(matches!(error, Error::Transport(_)), "{error}");
+    assert_eq!(transport.calls.load(Ordering::SeqCst), 3);
+}
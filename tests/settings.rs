@@ -0,0 +1,32 @@
+//! Checks of the diff between current and desired setting options.
+
+use std::collections::BTreeMap;
+
+use rust_woocommerce::settings::{SettingChange, SettingOption, SettingsDiff};
+use serde_json::json;
+
+#[test]
+fn diff() {
+    let current: Vec<SettingOption> = serde_json::from_value(json!([
+        {"id": "woocommerce_currency", "label": "Currency", "type": "select", "value": "USD", "options": {"USD": "US dollar", "EUR": "Euro"}},
+        {"id": "woocommerce_price_num_decimals", "label": "Decimals", "type": "number", "value": "2"},
+        {"id": "woocommerce_calc_taxes", "label": "Taxes", "type": "checkbox", "value": "no"}
+    ]))
+    .unwrap();
+    let desired = BTreeMap::from([
+        (String::from("woocommerce_currency"), json!("EUR")),
+        (String::from("woocommerce_price_num_decimals"), json!(2)),
+        (String::from("woocommerce_calc_taxes"), json!(false)),
+        (String::from("woocommerce_missing"), json!("x")),
+    ]);
+    let diff = SettingsDiff::new("general", &current, &desired);
+    assert_eq!(
+        diff.changes,
+        vec![SettingChange {
+            id: String::from("woocommerce_currency"),
+            current: json!("USD"),
+            desired: json!("EUR"),
+        }]
+    );
+    assert_eq!(diff.unknown, vec![String::from("woocommerce_missing")]);
+}
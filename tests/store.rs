@@ -0,0 +1,81 @@
+//! Checks of declarative store configuration files and plans.
+
+use rust_woocommerce::store::{StoreConfig, StorePlan};
+use rust_woocommerce::Error;
+
+#[test]
+fn config_files() {
+    let config: StoreConfig = toml::from_str(
+        r#"
+        tax_classes = ["Reduced rate"]
+
+        [[tax_rates]]
+        country = "DE"
+        rate = "19"
+        name = "MwSt"
+
+        [[shipping_zones]]
+        name = "Germany"
+        locations = [{ code = "DE", type = "country" }]
+        methods = [{ method_id = "flat_rate", settings = { cost = "5" } }]
+
+        [payment_gateways.bacs]
+        enabled = true
+        settings = { instructions = "Pay within 3 days" }
+
+        [settings.general]
+        woocommerce_currency = "EUR"
+
+        [[webhooks]]
+        name = "Orders"
+        topic = "order.created"
+        delivery_url = "https://example.com/hooks/orders"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.tax_rates[0].priority, 1);
+    assert!(config.tax_rates[0].shipping);
+    assert_eq!(config.tax_rates[0].class, "standard");
+    assert!(config.shipping_zones[0].methods[0].enabled);
+    assert_eq!(config.payment_gateways["bacs"].enabled, Some(true));
+    assert_eq!(config.settings["general"]["woocommerce_currency"], "EUR");
+    assert!(StoreConfig::default().is_empty());
+    let yaml = r#"
+        tax_classes: [Reduced rate]
+        tax_rates:
+          - { country: DE, rate: "19", name: MwSt }
+        shipping_zones:
+          - name: Germany
+            locations: [{ code: DE, type: country }]
+            methods: [{ method_id: flat_rate, settings: { cost: "5" } }]
+        payment_gateways:
+          bacs:
+            enabled: true
+            settings: { instructions: Pay within 3 days }
+        settings:
+          general: { woocommerce_currency: EUR }
+        webhooks:
+          - name: Orders
+            topic: order.created
+            delivery_url: https://example.com/hooks/orders
+        "#;
+    assert_eq!(StoreConfig::from_yaml(yaml).unwrap(), config);
+    let path = std::env::temp_dir().join(format!("woo-store-{}.yml", std::process::id()));
+    std::fs::write(&path, yaml).unwrap();
+    assert_eq!(StoreConfig::from_path(&path).unwrap(), config);
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        StoreConfig::from_path("store.json"),
+        Err(Error::Config(_))
+    ));
+}
+
+#[test]
+fn plan_display() {
+    let plan = StorePlan {
+        create_tax_classes: vec![String::from("Reduced rate")],
+        ..Default::default()
+    };
+    assert_eq!(plan.to_string(), "+ tax class Reduced rate\n");
+    assert_eq!(StorePlan::default().to_string(), "No changes\n");
+}
@@ -0,0 +1,42 @@
+//! Checks of the checkpoint stores of the incremental sync engine.
+
+use rust_woocommerce::{
+    Checkpoint, CheckpointStore, Cursor, Customer, FileCheckpointStore, MemoryCheckpointStore,
+    Order, Product, Syncable,
+};
+
+#[tokio::test]
+async fn checkpoint_stores() {
+    let checkpoint = Checkpoint {
+        cursor: Some(Cursor {
+            modified_gmt: "2024-05-01T10:00:00Z".parse().unwrap(),
+            id: 42,
+        }),
+        ids: [7, 42].into_iter().collect(),
+        recent: [(42, "2024-05-01T10:00:00Z".parse().unwrap())]
+            .into_iter()
+            .collect(),
+        reconciled_at: None,
+    };
+    let memory = MemoryCheckpointStore::new();
+    assert_eq!(memory.load(Product::KEY).await.unwrap(), None);
+    memory.save(Product::KEY, &checkpoint).await.unwrap();
+    assert_eq!(
+        memory.load(Product::KEY).await.unwrap(),
+        Some(checkpoint.clone())
+    );
+    let path = std::env::temp_dir().join(format!("woo-sync-{}.json", std::process::id()));
+    let file = FileCheckpointStore::new(&path);
+    assert_eq!(file.load(Order::KEY).await.unwrap(), None);
+    file.save(Order::KEY, &checkpoint).await.unwrap();
+    file.save(Customer::KEY, &Checkpoint::default())
+        .await
+        .unwrap();
+    let reopened = FileCheckpointStore::new(&path);
+    assert_eq!(reopened.load(Order::KEY).await.unwrap(), Some(checkpoint));
+    assert_eq!(
+        reopened.load(Customer::KEY).await.unwrap(),
+        Some(Checkpoint::default())
+    );
+    std::fs::remove_file(path).unwrap();
+}
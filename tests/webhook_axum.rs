@@ -0,0 +1,113 @@
+//! Checks of the axum webhook extractor, run with `--features axum`.
+#![cfg(feature = "axum")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::extract::FromRequest;
+use axum::http::{Request, StatusCode};
+use axum::response::IntoResponse;
+use rust_woocommerce::{
+    MemoryDeliveryStore, VerifiedWebhook, WebhookEvent, WebhookReceiver, WebhookRejection,
+    WebhookVerifier,
+};
+
+fn request(signature: &str, delivery_id: &str, body: &'static str) -> Request<Body> {
+    Request::post("/hooks")
+        .header("X-WC-Webhook-Topic", "coupon.deleted")
+        .header("X-WC-Webhook-Resource", "coupon")
+        .header("X-WC-Webhook-Event", "deleted")
+        .header("X-WC-Webhook-Signature", signature)
+        .header("X-WC-Webhook-Delivery-ID", delivery_id)
+        .body(Body::from(body))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn extractor() {
+    let receiver =
+        WebhookReceiver::new("secret").with_store(Arc::new(MemoryDeliveryStore::default()));
+    let body = r#"{"id":5}"#;
+    let signature = WebhookVerifier::new("secret").sign(body.as_bytes());
+
+    let VerifiedWebhook(delivery) =
+        VerifiedWebhook::from_request(request(&signature, "1", body), &receiver)
+            .await
+            .unwrap();
+    assert!(matches!(
+        delivery.event,
+        WebhookEvent::Deleted { id: 5, .. }
+    ));
+    receiver.commit(&delivery).await.unwrap();
+
+    let duplicate = VerifiedWebhook::from_request(request(&signature, "1", body), &receiver)
+        .await
+        .unwrap_err();
+    assert!(matches!(duplicate, WebhookRejection::Duplicate(_)));
+    assert_eq!(duplicate.into_response().status(), StatusCode::OK);
+
+    let forged = VerifiedWebhook::from_request(request("AAAA", "2", body), &receiver)
+        .await
+        .unwrap_err();
+    assert_eq!(forged.into_response().status(), StatusCode::UNAUTHORIZED);
+
+    // A forged delivery must not mark its ID as handled
+    assert!(
+        VerifiedWebhook::from_request(request(&signature, "2", body), &receiver)
+            .await
+            .is_ok()
+    );
+
+    let ping = Request::post("/hooks")
+        .body(Body::from("webhook_id=3"))
+        .unwrap();
+    let VerifiedWebhook(delivery) = VerifiedWebhook::from_request(ping, &receiver)
+        .await
+        .unwrap();
+    assert!(delivery.event.is_ping());
+}
+
+/// Handler that fails on its first call, recording the delivery only when it succeeds
+async fn flaky_handler(
+    receiver: &WebhookReceiver,
+    calls: &AtomicUsize,
+    VerifiedWebhook(delivery): VerifiedWebhook,
+) -> StatusCode {
+    if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+        return StatusCode::INTERNAL_SERVER_ERROR;
+    }
+    receiver.commit(&delivery).await.unwrap();
+    StatusCode::OK
+}
+
+#[tokio::test]
+async fn failed_handler_gets_redelivery() {
+    let receiver =
+        WebhookReceiver::new("secret").with_store(Arc::new(MemoryDeliveryStore::default()));
+    let body = r#"{"id":5}"#;
+    let signature = WebhookVerifier::new("secret").sign(body.as_bytes());
+    let calls = AtomicUsize::new(0);
+    let mut statuses = Vec::new();
+    for _ in 0..3 {
+        let status =
+            match VerifiedWebhook::from_request(request(&signature, "7", body), &receiver).await {
+                Ok(webhook) => flaky_handler(&receiver, &calls, webhook).await,
+                Err(rejection) => {
+                    assert!(matches!(rejection, WebhookRejection::Duplicate(_)));
+                    rejection.into_response().status()
+                }
+            };
+        statuses.push(status);
+    }
+    assert_eq!(
+        statuses,
+        vec![
+            StatusCode::INTERNAL_SERVER_ERROR,
+            StatusCode::OK,
+            StatusCode::OK
+        ]
+    );
+    // The retry after the failure reached the handler, the one after the success did not
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
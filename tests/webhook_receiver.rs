@@ -0,0 +1,54 @@
+//! Checks of webhook signature verification and payload parsing.
+
+use rust_woocommerce::webhooks::{Resource, Topic, WebhookEvent, WebhookHeaders, WebhookVerifier};
+use rust_woocommerce::WebhookError;
+
+#[test]
+fn verify_and_parse() {
+    let verifier = WebhookVerifier::new("secret");
+    let body = br#"{"id":42}"#;
+    let signature = verifier.sign(body);
+    let headers = WebhookHeaders::from_pairs([
+        ("X-WC-Webhook-Topic", "product.deleted"),
+        ("X-WC-Webhook-Resource", "product"),
+        ("X-WC-Webhook-Event", "deleted"),
+        ("X-WC-Webhook-Signature", signature.as_str()),
+        ("X-WC-Webhook-ID", "7"),
+        ("X-WC-Webhook-Delivery-ID", "abc"),
+    ]);
+    assert_eq!(headers.webhook_id, Some(7));
+    assert_eq!(headers.delivery_id.as_deref(), Some("abc"));
+    let delivery = verifier.verify(headers.clone(), body).unwrap();
+    assert!(matches!(
+        delivery.event,
+        WebhookEvent::Deleted {
+            resource: Resource::Product,
+            id: 42
+        }
+    ));
+    assert!(matches!(
+        verifier.verify(headers.clone(), br#"{"id":43}"#),
+        Err(WebhookError::InvalidSignature)
+    ));
+    assert!(matches!(
+        WebhookVerifier::new("other").verify(headers, body),
+        Err(WebhookError::InvalidSignature)
+    ));
+    let unsigned = WebhookHeaders::from_pairs([("x-wc-webhook-topic", "order.created")]);
+    assert!(matches!(
+        verifier.verify(unsigned.clone(), body),
+        Err(WebhookError::MissingHeader(_))
+    ));
+    let ping = verifier.verify(unsigned, b"webhook_id=7").unwrap();
+    assert!(matches!(ping.event, WebhookEvent::Ping { webhook_id: 7 }));
+    let action =
+        WebhookHeaders::from_pairs([("x-wc-webhook-topic", "action.woocommerce_add_to_cart")]);
+    let event = WebhookEvent::parse(
+        &action,
+        br#"{"action":"woocommerce_add_to_cart","arg":"x"}"#,
+    )
+    .unwrap();
+    assert!(
+        matches!(event, WebhookEvent::Other { topic, .. } if topic == Topic::action("woocommerce_add_to_cart"))
+    );
+}